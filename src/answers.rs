@@ -0,0 +1,225 @@
+use anyhow::{bail, Context, Result};
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+// unsolved parts and the missing second part of the last day answer "?" (or "??"), which is
+// not worth recording
+pub fn is_placeholder(answer: &str) -> bool {
+    !answer.is_empty() && answer.chars().all(|c| c == '?')
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Key {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub input: String,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    entries: BTreeMap<Key, String>,
+}
+
+impl Answers {
+    pub fn path() -> PathBuf {
+        PathBuf::from(ANSWERS_FILE)
+    }
+
+    pub fn load(path: &Path) -> Result<Answers> {
+        if !path.exists() {
+            return Ok(Default::default());
+        }
+        let s = fs::read_to_string(path)
+            .with_context(|| format!("Unable to read answers {}", path.display()))?;
+        Self::parse(&s).with_context(|| format!("Invalid answers file {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.to_string())
+            .with_context(|| format!("Unable to write answers {}", path.display()))
+    }
+
+    pub fn parse(s: &str) -> Result<Answers> {
+        let header = Regex::new(r#"^\[([0-9]+)\.day([0-9]+)\."(.+)"\]$"#)?;
+        let entry = Regex::new(r#"^part([12]) = "(.*)"$"#)?;
+
+        let mut answers = Answers::default();
+        let mut section: Option<(u32, u32, String)> = None;
+
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(c) = header.captures(line) {
+                section = Some((c[1].parse()?, c[2].parse()?, unescape(&c[3])?));
+            } else if let Some(c) = entry.captures(line) {
+                let (year, day, input) = match &section {
+                    None => bail!(
+                        "line {}: answer outside of a [YEAR.dayN.\"file\"] section",
+                        i + 1
+                    ),
+                    Some(x) => x.clone(),
+                };
+                let key = Key {
+                    year,
+                    day,
+                    part: c[1].parse()?,
+                    input,
+                };
+                answers.entries.insert(key, unescape(&c[2])?);
+            } else {
+                bail!("line {}: unable to parse {:?}", i + 1, line);
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub fn get(&self, year: u32, day: u32, part: u32, input: &str) -> Option<&String> {
+        self.entries.get(&Key {
+            year,
+            day,
+            part,
+            input: input.to_string(),
+        })
+    }
+
+    pub fn set(&mut self, year: u32, day: u32, part: u32, input: &str, answer: &str) {
+        let key = Key {
+            year,
+            day,
+            part,
+            input: input.to_string(),
+        };
+        self.entries.insert(key, answer.to_string());
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Key, &String)> {
+        self.entries.iter()
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut section = None;
+        for (key, answer) in self.entries.iter() {
+            if section != Some((key.year, key.day, &key.input)) {
+                if section.is_some() {
+                    writeln!(f)?;
+                }
+                writeln!(
+                    f,
+                    "[{}.day{}.\"{}\"]",
+                    key.year,
+                    key.day,
+                    escape(&key.input)
+                )?;
+                section = Some((key.year, key.day, &key.input));
+            }
+            writeln!(f, "part{} = \"{}\"", key.part, escape(answer))?;
+        }
+        Ok(())
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn unescape(s: &str) -> Result<String> {
+    let mut r = String::new();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            r.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('\\') => r.push('\\'),
+            Some('"') => r.push('"'),
+            Some('n') => r.push('\n'),
+            x => bail!(
+                "invalid escape sequence \\{}",
+                x.map(String::from).unwrap_or_default()
+            ),
+        }
+    }
+    Ok(r)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::{is_placeholder, Answers};
+    use indoc::indoc;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(indoc!(
+            r#"
+            # accepted answers
+            [2021.day1."2021/day1.txt"]
+            part1 = "1292"
+            part2 = "1262"
+
+            [2021.day13."2021/day13.txt"]
+            part2 = "X.\n.X"
+
+            [2020.day1."2020/day1.txt"]
+            part1 = "514579"
+            "#
+        ))
+        .unwrap();
+
+        let get = |year, day, part, input| answers.get(year, day, part, input).map(|x| x.as_str());
+        assert_eq!(Some("1292"), get(2021, 1, 1, "2021/day1.txt"));
+        assert_eq!(Some("1262"), get(2021, 1, 2, "2021/day1.txt"));
+        assert_eq!(Some("X.\n.X"), get(2021, 13, 2, "2021/day13.txt"));
+        assert_eq!(None, get(2021, 13, 1, "2021/day13.txt"));
+        assert_eq!(None, get(2021, 1, 1, "other.txt"));
+        // the same day of another year has its own answers
+        assert_eq!(Some("514579"), get(2020, 1, 1, "2020/day1.txt"));
+        assert_eq!(None, get(2020, 1, 1, "2021/day1.txt"));
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut answers = Answers::default();
+        answers.set(2021, 2, 1, "day2.txt", "a \"quoted\" \\ answer");
+        answers.set(2021, 1, 2, "day1.txt", "2");
+        answers.set(2021, 1, 1, "day1.txt", "1");
+
+        let s = answers.to_string();
+        assert_eq!(
+            "[2021.day1.\"day1.txt\"]\npart1 = \"1\"\npart2 = \"2\"\n\n[2021.day2.\"day2.txt\"]\npart1 = \"a \\\"quoted\\\" \\\\ answer\"\n",
+            s
+        );
+        assert_eq!(answers, Answers::parse(&s).unwrap());
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(Answers::parse("part1 = \"1\"").is_err());
+        assert!(Answers::parse("[2021.day1.\"day1.txt\"]\npart3 = \"1\"").is_err());
+        // answers without a year
+        assert!(Answers::parse("[day1.\"day1.txt\"]\npart1 = \"1\"").is_err());
+    }
+
+    #[test]
+    fn test_is_placeholder() {
+        assert!(is_placeholder("?"));
+        assert!(is_placeholder("??"));
+        assert!(!is_placeholder(""));
+        assert!(!is_placeholder("?1"));
+        assert!(!is_placeholder("14627"));
+    }
+}
@@ -0,0 +1,363 @@
+use crate::Day;
+use anyhow::{anyhow, bail, Context, Result};
+use itertools::Itertools;
+use serde_json::{json, Value};
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+pub type Bench = fn(&str, u32, &Options) -> Result<Timings>;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Options {
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            warmup: 3,
+            iterations: 10,
+        }
+    }
+}
+
+pub struct Timings {
+    pub parse: Vec<Duration>,
+    pub solve: Vec<Duration>,
+}
+
+pub fn measure<D: Day>(input: &str, part: u32, options: &Options) -> Result<Timings> {
+    let mut timings = Timings {
+        parse: vec![],
+        solve: vec![],
+    };
+
+    for i in 0..options.warmup + options.iterations {
+        let start = Instant::now();
+        let parsed = D::parse(input)?;
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        let answer = match part {
+            1 => D::part1(&parsed)?,
+            _ => D::part2(&parsed)?,
+        };
+        let solve = start.elapsed();
+        black_box(answer);
+
+        if i >= options.warmup {
+            timings.parse.push(parse);
+            timings.solve.push(solve);
+        }
+    }
+
+    Ok(timings)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty());
+        let sorted = samples.iter().sorted().collect_vec();
+        let p95 = (sorted.len() * 95).div_ceil(100).max(1) - 1;
+        Stats {
+            min: *sorted[0],
+            median: *sorted[sorted.len() / 2],
+            p95: *sorted[p95],
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl Record {
+    pub fn new(year: u32, day: u32, part: u32, timings: &Timings) -> Record {
+        Record {
+            year,
+            day,
+            part,
+            parse: Stats::from_samples(&timings.parse),
+            solve: Stats::from_samples(&timings.solve),
+        }
+    }
+
+    pub fn median(&self) -> Duration {
+        self.parse.median + self.solve.median
+    }
+}
+
+const CSV_HEADER: &str =
+    "year,day,part,parse_min_ns,parse_median_ns,parse_p95_ns,solve_min_ns,solve_median_ns,solve_p95_ns";
+
+pub fn to_csv(records: &[Record]) -> String {
+    let mut s = String::from(CSV_HEADER);
+    s.push('\n');
+    for r in records {
+        let cols = [r.parse.min, r.parse.median, r.parse.p95]
+            .iter()
+            .chain([r.solve.min, r.solve.median, r.solve.p95].iter())
+            .map(|d| d.as_nanos())
+            .join(",");
+        s += &format!("{},{},{},{}\n", r.year, r.day, r.part, cols);
+    }
+    s
+}
+
+pub fn from_csv(s: &str) -> Result<Vec<Record>> {
+    s.lines()
+        .skip(1)
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| {
+            let cols: Vec<u64> = l
+                .split(',')
+                .map(|x| x.trim().parse::<u64>())
+                .try_collect()
+                .with_context(|| format!("Unable to parse {:?}", l))?;
+            if cols.len() != 9 {
+                bail!("Expected 9 columns in {:?}", l);
+            }
+            let ns = Duration::from_nanos;
+            Ok(Record {
+                year: cols[0] as u32,
+                day: cols[1] as u32,
+                part: cols[2] as u32,
+                parse: Stats {
+                    min: ns(cols[3]),
+                    median: ns(cols[4]),
+                    p95: ns(cols[5]),
+                },
+                solve: Stats {
+                    min: ns(cols[6]),
+                    median: ns(cols[7]),
+                    p95: ns(cols[8]),
+                },
+            })
+        })
+        .try_collect()
+}
+
+fn stats_to_json(stats: &Stats) -> Value {
+    json!({
+        "min_ns": stats.min.as_nanos() as u64,
+        "median_ns": stats.median.as_nanos() as u64,
+        "p95_ns": stats.p95.as_nanos() as u64,
+    })
+}
+
+fn stats_from_json(v: &Value) -> Option<Stats> {
+    let ns = |k: &str| v[k].as_u64().map(Duration::from_nanos);
+    Some(Stats {
+        min: ns("min_ns")?,
+        median: ns("median_ns")?,
+        p95: ns("p95_ns")?,
+    })
+}
+
+fn record_from_json(r: &Value) -> Option<Record> {
+    Some(Record {
+        year: r["year"].as_u64()? as u32,
+        day: r["day"].as_u64()? as u32,
+        part: r["part"].as_u64()? as u32,
+        parse: stats_from_json(&r["parse"])?,
+        solve: stats_from_json(&r["solve"])?,
+    })
+}
+
+pub fn to_json(records: &[Record]) -> String {
+    let records = records
+        .iter()
+        .map(|r| {
+            json!({
+                "year": r.year,
+                "day": r.day,
+                "part": r.part,
+                "parse": stats_to_json(&r.parse),
+                "solve": stats_to_json(&r.solve),
+            })
+        })
+        .collect_vec();
+    serde_json::to_string_pretty(&json!({ "results": records })).unwrap()
+}
+
+pub fn from_json(s: &str) -> Result<Vec<Record>> {
+    let v: Value = serde_json::from_str(s)?;
+    v["results"]
+        .as_array()
+        .ok_or_else(|| anyhow!("Missing results array"))?
+        .iter()
+        .map(|r| record_from_json(r).ok_or_else(|| anyhow!("Invalid result {}", r)))
+        .try_collect()
+}
+
+fn is_csv(path: &Path) -> bool {
+    path.extension().is_some_and(|x| x == "csv")
+}
+
+pub fn save(path: &Path, records: &[Record]) -> Result<()> {
+    let s = if is_csv(path) {
+        to_csv(records)
+    } else {
+        to_json(records)
+    };
+    fs::write(path, s).with_context(|| format!("Unable to write {}", path.display()))
+}
+
+pub fn load(path: &Path) -> Result<Vec<Record>> {
+    let s =
+        fs::read_to_string(path).with_context(|| format!("Unable to read {}", path.display()))?;
+    if is_csv(path) {
+        from_csv(&s)
+    } else {
+        from_json(&s)
+    }
+    .with_context(|| format!("Invalid benchmark results {}", path.display()))
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Regression {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl Regression {
+    pub fn percent(&self) -> f64 {
+        (self.after.as_secs_f64() / self.before.as_secs_f64() - 1.0) * 100.0
+    }
+}
+
+// compares the median of parse + solve, threshold is in percent
+pub fn regressions(baseline: &[Record], current: &[Record], threshold: f64) -> Vec<Regression> {
+    current
+        .iter()
+        .filter_map(|r| {
+            let before = baseline
+                .iter()
+                .find(|b| (b.year, b.day, b.part) == (r.year, r.day, r.part))?
+                .median();
+            let after = r.median();
+            if after.as_secs_f64() > before.as_secs_f64() * (1.0 + threshold / 100.0) {
+                Some(Regression {
+                    year: r.year,
+                    day: r.day,
+                    part: r.part,
+                    before,
+                    after,
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::{
+        from_csv, from_json, measure, regressions, to_csv, to_json, Options, Record, Stats,
+    };
+    use crate::y2021::day1::Day1;
+    use std::time::Duration;
+
+    fn ms(x: u64) -> Duration {
+        Duration::from_millis(x)
+    }
+
+    fn record(year: u32, day: u32, part: u32, parse: u64, solve: u64) -> Record {
+        Record {
+            year,
+            day,
+            part,
+            parse: Stats {
+                min: ms(parse),
+                median: ms(parse),
+                p95: ms(parse),
+            },
+            solve: Stats {
+                min: ms(solve),
+                median: ms(solve + 1),
+                p95: ms(solve + 2),
+            },
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let samples = (1..=20).rev().map(ms).collect::<Vec<_>>();
+        assert_eq!(
+            Stats {
+                min: ms(1),
+                median: ms(11),
+                p95: ms(19)
+            },
+            Stats::from_samples(&samples)
+        );
+        assert_eq!(
+            Stats {
+                min: ms(5),
+                median: ms(5),
+                p95: ms(5)
+            },
+            Stats::from_samples(&[ms(5)])
+        );
+    }
+
+    #[test]
+    fn test_measure() {
+        let options = Options {
+            warmup: 2,
+            iterations: 5,
+        };
+        let timings = measure::<Day1>("199\n200\n208", 1, &options).unwrap();
+        assert_eq!(5, timings.parse.len());
+        assert_eq!(5, timings.solve.len());
+        assert!(measure::<Day1>("x", 1, &options).is_err());
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let records = vec![record(2021, 1, 1, 3, 10), record(2020, 19, 2, 1, 2000)];
+        assert_eq!(records, from_csv(&to_csv(&records)).unwrap());
+        assert_eq!(records, from_json(&to_json(&records)).unwrap());
+    }
+
+    #[test]
+    fn test_regressions() {
+        let baseline = vec![
+            record(2021, 1, 1, 0, 99),
+            record(2021, 2, 1, 0, 99),
+            record(2020, 2, 1, 0, 200),
+        ];
+        let current = vec![
+            record(2021, 1, 1, 0, 109),
+            record(2021, 2, 1, 0, 111),
+            record(2021, 3, 1, 0, 500),
+            // faster than 2020 day 2, which it is compared with, but not than 2021 day 2
+            record(2020, 2, 1, 0, 150),
+        ];
+
+        let r = regressions(&baseline, &current, 10.0);
+        assert_eq!(1, r.len());
+        assert_eq!((2021, 2, 1), (r[0].year, r[0].day, r[0].part));
+        assert_eq!(ms(112), r[0].after);
+        assert!((r[0].percent() - 12.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,207 @@
+use anyhow::{anyhow, Context, Result};
+use itertools::Itertools;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+pub const CACHE_FILE: &str = "cache.json";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Key {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub input_hash: String,
+    pub build: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Entry {
+    pub key: Key,
+    pub answer: String,
+    // how long computing the answer took
+    pub elapsed: Duration,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Cache {
+    entries: Vec<Entry>,
+}
+
+impl Cache {
+    pub fn path() -> PathBuf {
+        PathBuf::from(CACHE_FILE)
+    }
+
+    pub fn load(path: &Path) -> Result<Cache> {
+        if !path.exists() {
+            return Ok(Default::default());
+        }
+        let s = fs::read_to_string(path)
+            .with_context(|| format!("Unable to read cache {}", path.display()))?;
+        Self::from_json(&s).with_context(|| format!("Invalid cache {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.to_json())
+            .with_context(|| format!("Unable to write cache {}", path.display()))
+    }
+
+    pub fn to_json(&self) -> String {
+        let entries = self
+            .entries
+            .iter()
+            .map(|e| {
+                json!({
+                    "year": e.key.year,
+                    "day": e.key.day,
+                    "part": e.key.part,
+                    "input_hash": e.key.input_hash,
+                    "build": e.key.build,
+                    "answer": e.answer,
+                    "time_ns": e.elapsed.as_nanos() as u64,
+                })
+            })
+            .collect_vec();
+        serde_json::to_string_pretty(&json!({ "entries": entries })).unwrap()
+    }
+
+    pub fn from_json(s: &str) -> Result<Cache> {
+        let v: Value = serde_json::from_str(s)?;
+        let string = |e: &Value, field: &str| -> Result<String> {
+            Ok(e[field]
+                .as_str()
+                .ok_or_else(|| anyhow!("Invalid {}", field))?
+                .to_string())
+        };
+        let number = |e: &Value, field: &str| -> Result<u64> {
+            e[field]
+                .as_u64()
+                .ok_or_else(|| anyhow!("Invalid {}", field))
+        };
+        let entries = v["entries"]
+            .as_array()
+            .ok_or_else(|| anyhow!("Missing entries array"))?
+            .iter()
+            .map(|e| {
+                Ok(Entry {
+                    key: Key {
+                        year: number(e, "year")? as u32,
+                        day: number(e, "day")? as u32,
+                        part: number(e, "part")? as u32,
+                        input_hash: string(e, "input_hash")?,
+                        build: string(e, "build")?,
+                    },
+                    answer: string(e, "answer")?,
+                    elapsed: Duration::from_nanos(number(e, "time_ns")?),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Cache { entries })
+    }
+
+    pub fn get(&self, key: &Key) -> Option<&Entry> {
+        self.entries.iter().find(|e| e.key == *key)
+    }
+
+    // answers of older builds for the same input are replaced, they cannot be hit again
+    pub fn insert(&mut self, entry: Entry) {
+        let k = &entry.key;
+        self.entries.retain(|e| {
+            (e.key.year, e.key.day, e.key.part, &e.key.input_hash)
+                != (k.year, k.day, k.part, &k.input_hash)
+        });
+        self.entries.push(entry);
+        self.entries
+            .sort_by_key(|e| (e.key.year, e.key.day, e.key.part));
+    }
+
+    // removes the entries of a year, a day of a year or all of them, returns how many were removed
+    pub fn clear(&mut self, year: Option<u32>, day: Option<u32>) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(|e| match (year, day) {
+            (None, _) => false,
+            (Some(y), None) => e.key.year != y,
+            (Some(y), Some(d)) => (e.key.year, e.key.day) != (y, d),
+        });
+        before - self.entries.len()
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+}
+
+// the hash of the running executable, every change of the code changes it
+pub fn build_fingerprint() -> Option<&'static str> {
+    static FINGERPRINT: OnceLock<Option<String>> = OnceLock::new();
+    FINGERPRINT
+        .get_or_init(|| {
+            let bytes = fs::read(std::env::current_exe().ok()?).ok()?;
+            Some(
+                Sha256::digest(&bytes)
+                    .iter()
+                    .map(|b| format!("{:02x}", b))
+                    .collect(),
+            )
+        })
+        .as_deref()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cache::{build_fingerprint, Cache, Entry, Key};
+    use std::time::Duration;
+
+    fn entry(day: u32, part: u32, input_hash: &str, build: &str, answer: &str) -> Entry {
+        Entry {
+            key: Key {
+                year: 2021,
+                day,
+                part,
+                input_hash: input_hash.to_string(),
+                build: build.to_string(),
+            },
+            answer: answer.to_string(),
+            elapsed: Duration::from_millis(1500),
+        }
+    }
+
+    #[test]
+    fn test_cache() {
+        let mut cache = Cache::default();
+        cache.insert(entry(19, 1, "in", "b1", "79"));
+        cache.insert(entry(19, 2, "in", "b1", "3621"));
+        cache.insert(entry(23, 1, "in", "b1", "12521"));
+        assert_eq!(
+            "79",
+            cache.get(&entry(19, 1, "in", "b1", "").key).unwrap().answer
+        );
+        assert_eq!(None, cache.get(&entry(19, 1, "in", "b2", "").key));
+        assert_eq!(None, cache.get(&entry(19, 1, "other", "b1", "").key));
+
+        // a new build replaces the answer of the old one
+        cache.insert(entry(19, 1, "in", "b2", "80"));
+        assert_eq!(None, cache.get(&entry(19, 1, "in", "b1", "").key));
+        assert_eq!(3, cache.entries().len());
+
+        let json = cache.to_json();
+        assert_eq!(cache, Cache::from_json(&json).unwrap());
+        assert!(Cache::from_json("{}").is_err());
+
+        assert_eq!(0, cache.clear(Some(2020), None));
+        assert_eq!(2, cache.clear(Some(2021), Some(19)));
+        assert_eq!(23, cache.entries()[0].key.day);
+        assert_eq!(1, cache.clear(None, None));
+        assert!(cache.entries().is_empty());
+    }
+
+    #[test]
+    fn test_build_fingerprint() {
+        assert_eq!(64, build_fingerprint().unwrap().len());
+        assert_eq!(build_fingerprint(), build_fingerprint());
+    }
+}
@@ -0,0 +1,1129 @@
+use anyhow::{anyhow, bail, Context, Result};
+use aoc::answers::{is_placeholder, Answers};
+use aoc::bench::{self, Record};
+use aoc::cache::{self, Cache, Entry, Key};
+use aoc::gen;
+use aoc::inputs::{self, Input, Inputs, Source};
+use aoc::registry::{self, Puzzle};
+use aoc::runner::{self, Job, Status};
+use aoc::submit::{self, Client, Log, Verdict};
+use aoc::util::log::{self, Level};
+use aoc::{scaffold, util, viz};
+use itertools::Itertools;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const USAGE: &str = "usage: aoc [-v|-vv|-vvv] [--trace <day>] <command>
+    aoc run [<year>] <day> [--part 1|2] [--input <path>|-] [--timeout <seconds>]
+            [--stack <MiB>] [--format text|json] [--no-cache]
+    aoc run [<year>] --all [--jobs <n>] [--timeout <seconds>] [--stack <MiB>]
+                           [--format text|json] [--no-cache]
+    aoc verify [<year>] [<day>] [--bless]
+    aoc bench [<year>] [<day>] [--part 1|2] [--warmup <n>] [--iterations <n>]
+              [--output <file.json|file.csv>] [--baseline <file>] [--threshold <percent>]
+    aoc submit [<year>] <day> <part> [--input <path>|-]
+    aoc new [<year>] <day>
+    aoc list
+    aoc gen <day> [--size <n>] [--seed <n>] [--output <path>]
+    aoc viz [<year>] <day> [--input <path>|-] [--fps <n>] [--frames <n>] [--plain]
+    aoc cache list
+    aoc cache clear [<year>] [<day>]
+
+the year defaults to the latest one with solutions, a number above 25 is a year
+--format json prints one JSON record per part, including what the solution logged
+answers are cached per input and build in cache.json, --no-cache computes them again
+missing inputs are downloaded when AOC_SESSION is set to a session cookie,
+AOC_URL overrides https://adventofcode.com, submitting needs AOC_SESSION as well
+viz plays the frames on a terminal with [space] pause, [n] step, [+/-] speed and [q] quit,
+otherwise or with --plain it prints them one after another";
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Logging {
+    pub level: Option<Level>,
+    pub targets: Vec<(String, Level)>,
+}
+
+impl Logging {
+    pub fn apply(&self) {
+        if let Some(level) = self.level {
+            log::set_level(level);
+        }
+        for (target, level) in self.targets.iter() {
+            log::set_target_level(target, *level);
+        }
+    }
+}
+
+// takes the logging flags out of the arguments, they are accepted before and after the command
+pub fn parse_logging(args: &[String]) -> Result<(Logging, Vec<String>)> {
+    let mut logging = Logging::default();
+    let mut rest = vec![];
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-v" => logging.level = Some(Level::Info),
+            "-vv" => logging.level = Some(Level::Debug),
+            "-vvv" => logging.level = Some(Level::Trace),
+            "--trace" => {
+                let value = args.next().ok_or_else(|| anyhow!("--trace needs a day"))?;
+                let target = match value.parse::<u32>() {
+                    Ok(day) => format!("day{}", parse_day(&day.to_string())?),
+                    Err(_) => value.clone(),
+                };
+                logging.targets.push((target, Level::Trace));
+            }
+            _ => rest.push(arg.clone()),
+        }
+    }
+
+    Ok((logging, rest))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run {
+        year: Option<u32>,
+        day: Option<u32>,
+        part: Option<u32>,
+        input: Option<PathBuf>,
+        options: runner::Options,
+        format: Format,
+        cache: bool,
+    },
+    Verify {
+        year: Option<u32>,
+        day: Option<u32>,
+        bless: bool,
+    },
+    Bench {
+        year: Option<u32>,
+        day: Option<u32>,
+        part: Option<u32>,
+        options: bench::Options,
+        output: Option<PathBuf>,
+        baseline: Option<PathBuf>,
+        threshold: f64,
+    },
+    Submit {
+        year: Option<u32>,
+        day: u32,
+        part: u32,
+        input: Option<PathBuf>,
+    },
+    New {
+        year: Option<u32>,
+        day: u32,
+    },
+    List,
+    Gen {
+        day: u32,
+        size: usize,
+        seed: u64,
+        output: Option<PathBuf>,
+    },
+    Cache(CacheCommand),
+    Viz {
+        year: Option<u32>,
+        day: u32,
+        input: Option<PathBuf>,
+        options: viz::Options,
+    },
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CacheCommand {
+    List,
+    Clear { year: Option<u32>, day: Option<u32> },
+}
+
+pub fn parse_args(args: &[String]) -> Result<Command> {
+    let mut args = args.iter().map(|x| x.as_str());
+
+    match args.next() {
+        Some("run") => {
+            let mut year = None;
+            let mut day = None;
+            let mut part = None;
+            let mut input = None;
+            let mut all = false;
+            let mut options = runner::Options::default();
+            let mut format = Format::Text;
+            let mut cache = true;
+
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--all" => all = true,
+                    "--no-cache" => cache = false,
+                    "--timeout" | "-t" => {
+                        let value = args
+                            .next()
+                            .ok_or_else(|| anyhow!("--timeout needs a value"))?;
+                        let seconds: f64 = parse_number(arg, value)?;
+                        if seconds.is_nan() || seconds <= 0.0 {
+                            bail!("--timeout must be positive");
+                        }
+                        options.timeout = Duration::from_secs_f64(seconds);
+                    }
+                    "--jobs" | "-j" => {
+                        let value = args.next().ok_or_else(|| anyhow!("--jobs needs a value"))?;
+                        options.jobs = parse_number(arg, value)?;
+                        if options.jobs == 0 {
+                            bail!("--jobs must be at least 1");
+                        }
+                    }
+                    "--stack" => {
+                        let value = args
+                            .next()
+                            .ok_or_else(|| anyhow!("--stack needs a value"))?;
+                        let mib: usize = parse_number(arg, value)?;
+                        options.stack_size = mib * 1024 * 1024;
+                    }
+                    "--format" => {
+                        format = match args.next() {
+                            Some("text") => Format::Text,
+                            Some("json") => Format::Json,
+                            value => bail!("invalid format {:?}, expected text or json", value),
+                        }
+                    }
+                    "--part" | "-p" => {
+                        let value = args.next().ok_or_else(|| anyhow!("--part needs a value"))?;
+                        part = Some(parse_part(value)?);
+                    }
+                    "--input" | "-i" => {
+                        let value = args.next().ok_or_else(|| anyhow!("--input needs a path"))?;
+                        input = Some(PathBuf::from(value));
+                    }
+                    _ if !arg.starts_with('-') => parse_year_day(&mut year, &mut day, arg)?,
+                    _ => bail!("unexpected argument {:?}\n{}", arg, USAGE),
+                }
+            }
+
+            if all == day.is_some() {
+                bail!("run needs either a day or --all\n{}", USAGE);
+            }
+            if all && (part.is_some() || input.is_some()) {
+                bail!("--part and --input cannot be combined with --all");
+            }
+
+            Ok(Command::Run {
+                year,
+                day,
+                part,
+                input,
+                options,
+                format,
+                cache,
+            })
+        }
+        Some("verify") => {
+            let mut year = None;
+            let mut day = None;
+            let mut bless = false;
+
+            for arg in args {
+                match arg {
+                    "--bless" => bless = true,
+                    _ if !arg.starts_with('-') => parse_year_day(&mut year, &mut day, arg)?,
+                    _ => bail!("unexpected argument {:?}\n{}", arg, USAGE),
+                }
+            }
+
+            Ok(Command::Verify { year, day, bless })
+        }
+        Some("bench") => {
+            let mut year = None;
+            let mut day = None;
+            let mut part = None;
+            let mut options = bench::Options::default();
+            let mut output = None;
+            let mut baseline = None;
+            let mut threshold = 10.0;
+
+            while let Some(arg) = args.next() {
+                let mut value = || args.next().ok_or_else(|| anyhow!("{} needs a value", arg));
+                match arg {
+                    "--part" | "-p" => part = Some(parse_part(value()?)?),
+                    "--warmup" => options.warmup = parse_number(arg, value()?)?,
+                    "--iterations" | "-n" => options.iterations = parse_number(arg, value()?)?,
+                    "--output" | "-o" => output = Some(PathBuf::from(value()?)),
+                    "--baseline" => baseline = Some(PathBuf::from(value()?)),
+                    "--threshold" => threshold = parse_number(arg, value()?)?,
+                    _ if !arg.starts_with('-') => parse_year_day(&mut year, &mut day, arg)?,
+                    _ => bail!("unexpected argument {:?}\n{}", arg, USAGE),
+                }
+            }
+
+            if options.iterations == 0 {
+                bail!("--iterations must be at least 1");
+            }
+            if part.is_some() && day.is_none() {
+                bail!("--part needs a day");
+            }
+
+            Ok(Command::Bench {
+                year,
+                day,
+                part,
+                options,
+                output,
+                baseline,
+                threshold,
+            })
+        }
+        Some("submit") => {
+            let mut positional = vec![];
+            let mut input = None;
+
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--input" | "-i" => {
+                        let value = args.next().ok_or_else(|| anyhow!("--input needs a path"))?;
+                        input = Some(PathBuf::from(value));
+                    }
+                    _ if positional.len() < 3 && !arg.starts_with('-') => positional.push(arg),
+                    _ => bail!("unexpected argument {:?}\n{}", arg, USAGE),
+                }
+            }
+
+            let (year, day, part) = match positional[..] {
+                [year, day, part] => (Some(parse_year(year)?), day, part),
+                [day, part] => (None, day, part),
+                _ => bail!("submit needs a day and a part\n{}", USAGE),
+            };
+            Ok(Command::Submit {
+                year,
+                day: parse_day(day)?,
+                part: parse_part(part)?,
+                input,
+            })
+        }
+        Some("new") => match (args.next(), args.next(), args.next()) {
+            (Some(day), None, _) if !day.starts_with('-') => Ok(Command::New {
+                year: None,
+                day: parse_day(day)?,
+            }),
+            (Some(year), Some(day), None) => Ok(Command::New {
+                year: Some(parse_year(year)?),
+                day: parse_day(day)?,
+            }),
+            _ => bail!("new needs exactly one day\n{}", USAGE),
+        },
+        Some("list") => Ok(Command::List),
+        Some("cache") => match args.next() {
+            Some("list") if args.next().is_none() => Ok(Command::Cache(CacheCommand::List)),
+            Some("clear") => {
+                let (mut year, mut day) = (None, None);
+                for arg in args {
+                    parse_year_day(&mut year, &mut day, arg)?;
+                }
+                Ok(Command::Cache(CacheCommand::Clear { year, day }))
+            }
+            _ => bail!("cache needs list or clear [<year>] [<day>]\n{}", USAGE),
+        },
+        Some("gen") => {
+            let mut day = None;
+            let mut size = 10;
+            let mut seed = 2021;
+            let mut output = None;
+
+            while let Some(arg) = args.next() {
+                let mut value = || args.next().ok_or_else(|| anyhow!("{} needs a value", arg));
+                match arg {
+                    "--size" | "-n" => size = parse_number(arg, value()?)?,
+                    "--seed" | "-s" => seed = parse_number(arg, value()?)?,
+                    "--output" | "-o" => output = Some(PathBuf::from(value()?)),
+                    _ if day.is_none() && !arg.starts_with('-') => day = Some(parse_day(arg)?),
+                    _ => bail!("unexpected argument {:?}\n{}", arg, USAGE),
+                }
+            }
+
+            match day {
+                Some(day) => Ok(Command::Gen {
+                    day,
+                    size,
+                    seed,
+                    output,
+                }),
+                None => bail!("gen needs a day\n{}", USAGE),
+            }
+        }
+        Some("viz") => {
+            let mut year = None;
+            let mut day = None;
+            let mut input = None;
+            let mut options = viz::Options::default();
+
+            while let Some(arg) = args.next() {
+                let mut value = || args.next().ok_or_else(|| anyhow!("{} needs a value", arg));
+                match arg {
+                    "--input" | "-i" => input = Some(PathBuf::from(value()?)),
+                    "--fps" => {
+                        options.fps = parse_number(arg, value()?)?;
+                        if options.fps.is_nan() || options.fps <= 0.0 {
+                            bail!("--fps must be positive");
+                        }
+                    }
+                    "--frames" | "-n" => options.frames = parse_number(arg, value()?)?,
+                    "--plain" => options.plain = true,
+                    _ if !arg.starts_with('-') => parse_year_day(&mut year, &mut day, arg)?,
+                    _ => bail!("unexpected argument {:?}\n{}", arg, USAGE),
+                }
+            }
+
+            match day {
+                Some(day) => Ok(Command::Viz {
+                    year,
+                    day,
+                    input,
+                    options,
+                }),
+                None => bail!("viz needs a day\n{}", USAGE),
+            }
+        }
+        Some(other) => bail!("unknown command {:?}\n{}", other, USAGE),
+        None => bail!("{}", USAGE),
+    }
+}
+
+// `[<year>] <day>`, the first number is a year if it is too large to be a day
+fn parse_year_day(year: &mut Option<u32>, day: &mut Option<u32>, arg: &str) -> Result<()> {
+    match arg.parse::<u32>() {
+        Ok(x) if x > 25 && year.is_none() && day.is_none() => *year = Some(parse_year(arg)?),
+        _ if day.is_none() => *day = Some(parse_day(arg)?),
+        _ => bail!("unexpected argument {:?}\n{}", arg, USAGE),
+    }
+    Ok(())
+}
+
+fn parse_year(s: &str) -> Result<u32> {
+    match s.parse::<u32>() {
+        Ok(year) if year >= 2015 => Ok(year),
+        _ => bail!("invalid year {:?}, expected 2015 or later", s),
+    }
+}
+
+fn parse_day(s: &str) -> Result<u32> {
+    match s.parse::<u32>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => bail!("invalid day {:?}, expected 1-25", s),
+    }
+}
+
+fn parse_part(s: &str) -> Result<u32> {
+    match s {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => bail!("invalid part {:?}, expected 1 or 2", s),
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(flag: &str, s: &str) -> Result<T> {
+    s.parse()
+        .map_err(|_| anyhow!("invalid value {:?} for {}", s, flag))
+}
+
+// the log of every part goes to stderr after it finished, so that parallel parts don't mix
+fn print_log(outcome: &runner::Outcome) {
+    for line in &outcome.log {
+        eprintln!("{}", line);
+    }
+}
+
+fn solve(puzzle: &Puzzle, input: &Input) -> Result<String> {
+    let outcome = runner::run(puzzle, input, &runner::Options::default());
+    print_log(&outcome);
+    match outcome.status {
+        Status::Ok(answer) => Ok(answer),
+        status => bail!(
+            "{} day {} part {} failed: {}",
+            puzzle.year,
+            puzzle.day,
+            puzzle.part,
+            status
+        ),
+    }
+}
+
+fn cache_key(puzzle: &Puzzle, input: &Input) -> Option<Key> {
+    Some(Key {
+        year: puzzle.year,
+        day: puzzle.day,
+        part: puzzle.part,
+        input_hash: input.hash.clone(),
+        build: cache::build_fingerprint()?.to_string(),
+    })
+}
+
+// failures are reported per part instead of aborting the whole run
+fn run_puzzles(
+    puzzles: &[Puzzle],
+    input: &Option<PathBuf>,
+    options: &runner::Options,
+    format: Format,
+    use_cache: bool,
+) -> Result<()> {
+    let inputs = Inputs::from_env();
+    let source = Source::from_arg(input);
+    let cache_path = Cache::path();
+    let mut cache = Cache::load(&cache_path).unwrap_or_else(|e| {
+        aoc::warn!("Ignoring the cache: {:#}", e);
+        Cache::default()
+    });
+
+    let mut jobs = vec![];
+    for ((year, day), puzzles) in &puzzles.iter().group_by(|p| (p.year, p.day)) {
+        let input = inputs
+            .resolve(year, day, &source)
+            .map_err(|e| format!("{:#}", e));
+        for puzzle in puzzles {
+            let cached = match &input {
+                Ok(input) if use_cache => cache_key(puzzle, input)
+                    .and_then(|key| cache.get(&key))
+                    .cloned(),
+                _ => None,
+            };
+            jobs.push(Job {
+                puzzle: *puzzle,
+                input: input.clone(),
+                cached,
+            });
+        }
+    }
+
+    let outcomes = match format {
+        Format::Text => runner::run_all(&jobs, options, |_, o| {
+            print_log(o);
+            let cached = if o.cached { " (cached)" } else { "" };
+            println!("Day {} part {}: {}{}", o.day, o.part, o.status, cached)
+        }),
+        Format::Json => runner::run_all(&jobs, options, |job, o| {
+            println!("{}", job.to_json(o));
+        }),
+    };
+
+    let mut computed = 0;
+    for (job, o) in jobs.iter().zip(outcomes.iter()) {
+        let key = job
+            .input
+            .as_ref()
+            .ok()
+            .and_then(|i| cache_key(&job.puzzle, i));
+        if let (Status::Ok(answer), false, Some(key)) = (&o.status, o.cached, key) {
+            cache.insert(Entry {
+                key,
+                answer: answer.clone(),
+                elapsed: o.elapsed,
+            });
+            computed += 1;
+        }
+    }
+    if computed > 0 {
+        cache.save(&cache_path)?;
+    }
+
+    if format == Format::Text && outcomes.len() > 1 {
+        println!();
+        runner::print_summary(&outcomes);
+    }
+    let failed = outcomes.iter().filter(|o| !o.is_ok()).count();
+    if failed > 0 {
+        bail!("{} of {} parts failed", failed, outcomes.len());
+    }
+    Ok(())
+}
+
+fn verify(puzzles: &[Puzzle], bless: bool) -> Result<()> {
+    let inputs = Inputs::from_env();
+    let path = Answers::path();
+    let mut answers = Answers::load(&path)?;
+
+    let (mut ok, mut mismatched, mut missing, mut new) = (0, 0, 0, 0);
+    for puzzle in puzzles.iter() {
+        let input = puzzle.input_file();
+        let expected = answers
+            .get(puzzle.year, puzzle.day, puzzle.part, &input)
+            .cloned();
+        let label = format!("{} day {:>2} part {}", puzzle.year, puzzle.day, puzzle.part);
+
+        let actual = inputs
+            .get(puzzle.year, puzzle.day)
+            .and_then(|input| solve(puzzle, &input));
+        match (actual, expected) {
+            (Ok(actual), Some(expected)) if actual == expected => ok += 1,
+            (Ok(actual), Some(expected)) => {
+                mismatched += 1;
+                println!(
+                    "{}: MISMATCH expected {:?}, got {:?}",
+                    label, expected, actual
+                );
+                if bless {
+                    answers.set(puzzle.year, puzzle.day, puzzle.part, &input, &actual);
+                }
+            }
+            (Ok(actual), None) if is_placeholder(&actual) => {
+                println!("{}: skipped: placeholder {:?}", label, actual)
+            }
+            (Ok(actual), None) => {
+                new += 1;
+                println!("{}: new answer {:?}", label, actual);
+                if bless {
+                    answers.set(puzzle.year, puzzle.day, puzzle.part, &input, &actual);
+                }
+            }
+            (Err(e), Some(expected)) => {
+                missing += 1;
+                println!("{}: MISSING expected {:?}: {:#}", label, expected, e);
+            }
+            (Err(e), None) => println!("{}: skipped: {:#}", label, e),
+        }
+    }
+
+    println!(
+        "{} ok, {} mismatched, {} missing, {} new",
+        ok, mismatched, missing, new
+    );
+
+    if bless && mismatched + new > 0 {
+        answers.save(&path)?;
+        println!(
+            "Recorded {} answers in {}",
+            mismatched + new,
+            path.display()
+        );
+    } else if mismatched + missing > 0 {
+        bail!(
+            "{} answers differ from {}",
+            mismatched + missing,
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+fn run_bench(
+    puzzles: &[Puzzle],
+    options: &bench::Options,
+    output: &Option<PathBuf>,
+    baseline: &Option<PathBuf>,
+    threshold: f64,
+) -> Result<()> {
+    let inputs = Inputs::from_env();
+    let mut records = vec![];
+    for puzzle in puzzles {
+        let input = inputs.get(puzzle.year, puzzle.day)?;
+        let timings = (puzzle.bench)(input.trimmed(), puzzle.part, options)
+            .map_err(|e| util::name_input(e, &input.name))
+            .with_context(|| {
+                format!(
+                    "{} day {} part {} failed",
+                    puzzle.year, puzzle.day, puzzle.part
+                )
+            })?;
+        let record = Record::new(puzzle.year, puzzle.day, puzzle.part, &timings);
+
+        println!(
+            "{} day {:>2} part {}  parse min {:>10.2?} median {:>10.2?} p95 {:>10.2?}  solve min {:>10.2?} median {:>10.2?} p95 {:>10.2?}",
+            record.year,
+            record.day,
+            record.part,
+            record.parse.min,
+            record.parse.median,
+            record.parse.p95,
+            record.solve.min,
+            record.solve.median,
+            record.solve.p95
+        );
+        records.push(record);
+    }
+
+    if let Some(path) = output {
+        bench::save(path, &records)?;
+        println!("Wrote {} results to {}", records.len(), path.display());
+    }
+
+    if let Some(path) = baseline {
+        let regressions = bench::regressions(&bench::load(path)?, &records, threshold);
+        for r in regressions.iter() {
+            println!(
+                "{} day {:>2} part {}: REGRESSION median {:.2?} -> {:.2?} ({:+.1}%)",
+                r.year,
+                r.day,
+                r.part,
+                r.before,
+                r.after,
+                r.percent()
+            );
+        }
+        if !regressions.is_empty() {
+            bail!(
+                "{} regressions above {}% compared to {}",
+                regressions.len(),
+                threshold,
+                path.display()
+            );
+        }
+    }
+    Ok(())
+}
+
+fn submit_answer(year: u32, day: u32, part: u32, input: &Option<PathBuf>) -> Result<()> {
+    let session = match inputs::session_from_env() {
+        Some(x) => x,
+        None => bail!("set AOC_SESSION to submit answers"),
+    };
+    let client = Client {
+        url: inputs::url_from_env(),
+        session,
+    };
+    let puzzle = match registry::find(year, day, part) {
+        Some(x) => x,
+        None => bail!("{} day {} part {} is not implemented", year, day, part),
+    };
+
+    let source = Source::from_arg(input);
+    let input = Inputs::from_env().resolve(year, day, &source)?;
+    let answer = solve(&puzzle, &input)?;
+    println!("{} day {} part {}: {}", year, day, part, answer);
+
+    let response = submit::submit(&client, &Log::path(), year, day, part, &answer)?;
+    println!("{}: {}", response.verdict, response.message);
+    if let Some(wait) = response.wait {
+        println!("Next submission possible in {}s", wait.as_secs());
+    }
+
+    // the answers of cached inputs are checked by `aoc verify` from now on
+    if response.verdict == Verdict::Correct && source == Source::Cache {
+        let path = Answers::path();
+        let mut answers = Answers::load(&path)?;
+        answers.set(year, day, part, &puzzle.input_file(), &answer);
+        answers.save(&path)?;
+        println!("Recorded the answer in {}", path.display());
+    }
+    Ok(())
+}
+
+// the puzzles of a year, of a day or of a single part, the latest year if none is given
+fn select(year: Option<u32>, day: Option<u32>, part: Option<u32>) -> Result<Vec<Puzzle>> {
+    let year = year.unwrap_or_else(registry::latest_year);
+    let puzzles = registry::select(year, day, part);
+    if puzzles.is_empty() {
+        match day {
+            Some(day) => bail!("{} day {} is not implemented", year, day),
+            None => bail!(
+                "{} has no solutions, known years are {:?}",
+                year,
+                registry::years()
+            ),
+        }
+    }
+    Ok(puzzles)
+}
+
+pub fn execute(command: Command) -> Result<()> {
+    match command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            options,
+            format,
+            cache,
+        } => {
+            let puzzles = select(year, day, part)?;
+            run_puzzles(&puzzles, &input, &options, format, cache)?
+        }
+        Command::Verify { year, day, bless } => verify(&select(year, day, None)?, bless)?,
+        Command::Bench {
+            year,
+            day,
+            part,
+            options,
+            output,
+            baseline,
+            threshold,
+        } => {
+            let puzzles = select(year, day, part)?;
+            run_bench(&puzzles, &options, &output, &baseline, threshold)?
+        }
+        Command::Submit {
+            year,
+            day,
+            part,
+            input,
+        } => submit_answer(
+            year.unwrap_or_else(registry::latest_year),
+            day,
+            part,
+            &input,
+        )?,
+        Command::New { year, day } => {
+            let year = year.unwrap_or_else(registry::latest_year);
+            for path in scaffold::new_day(Path::new("."), year, day)? {
+                println!("Wrote {}", path.display());
+            }
+        }
+        Command::List => {
+            for year in registry::years() {
+                for day in registry::days(year) {
+                    let puzzles = registry::for_day(year, day);
+                    let input = puzzles[0].input_path();
+                    println!(
+                        "{} day {:>2}  parts {}  {}{}",
+                        year,
+                        day,
+                        puzzles.iter().map(|p| p.part).join(","),
+                        input.display(),
+                        if input.exists() { "" } else { " (missing)" }
+                    );
+                }
+            }
+        }
+        Command::Cache(CacheCommand::List) => {
+            let cache = Cache::load(&Cache::path())?;
+            let build = cache::build_fingerprint();
+            for e in cache.entries() {
+                println!(
+                    "{} day {:>2} part {}  {:>10.2?}  input {}  {}  {}",
+                    e.key.year,
+                    e.key.day,
+                    e.key.part,
+                    e.elapsed,
+                    &e.key.input_hash[..e.key.input_hash.len().min(8)],
+                    if Some(e.key.build.as_str()) == build {
+                        "current "
+                    } else {
+                        "outdated"
+                    },
+                    e.answer
+                );
+            }
+        }
+        Command::Cache(CacheCommand::Clear { year, day }) => {
+            let path = Cache::path();
+            let mut cache = Cache::load(&path)?;
+            // a day without a year is a day of the latest year
+            let year = year.or_else(|| day.map(|_| registry::latest_year()));
+            let removed = cache.clear(year, day);
+            cache.save(&path)?;
+            println!("Removed {} answers from {}", removed, path.display());
+        }
+        Command::Gen {
+            day,
+            size,
+            seed,
+            output,
+        } => {
+            let input = gen::generate(day, size, seed)?;
+            match output {
+                Some(path) => std::fs::write(&path, input + "\n")
+                    .with_context(|| format!("Unable to write {}", path.display()))?,
+                None => println!("{}", input),
+            }
+        }
+        Command::Viz {
+            year,
+            day,
+            input,
+            options,
+        } => {
+            let year = year.unwrap_or_else(registry::latest_year);
+            let visualizer = viz::visualizer(year, day)?;
+            let input = Inputs::from_env().resolve(year, day, &Source::from_arg(&input))?;
+            let frames =
+                visualizer(input.trimmed()).map_err(|e| util::name_input(e, &input.name))?;
+            viz::show(frames, &options)?
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::{parse_args, parse_logging, CacheCommand, Command, Format, Logging};
+    use aoc::bench::Options;
+    use aoc::util::log::Level;
+    use aoc::{runner, viz};
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(|x| x.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_run() {
+        assert_eq!(
+            Command::Run {
+                year: None,
+                day: Some(19),
+                part: Some(2),
+                input: Some(PathBuf::from("x.txt")),
+                options: runner::Options::default(),
+                format: Format::Text,
+                cache: true
+            },
+            parse_args(&args("run 19 --part 2 --input x.txt")).unwrap()
+        );
+        assert_eq!(
+            Command::Run {
+                year: None,
+                day: None,
+                part: None,
+                input: None,
+                options: runner::Options {
+                    timeout: Duration::from_millis(1500),
+                    stack_size: 64 * 1024 * 1024,
+                    jobs: 1
+                },
+                format: Format::Json,
+                cache: false
+            },
+            parse_args(&args(
+                "run --all --timeout 1.5 --stack 64 --jobs 1 --format json --no-cache"
+            ))
+            .unwrap()
+        );
+        assert_eq!(Command::List, parse_args(&args("list")).unwrap());
+        assert_eq!(
+            Command::New { year: None, day: 4 },
+            parse_args(&args("new 4")).unwrap()
+        );
+        assert_eq!(
+            Command::New {
+                year: Some(2022),
+                day: 4
+            },
+            parse_args(&args("new 2022 4")).unwrap()
+        );
+        assert_eq!(
+            Command::Submit {
+                year: None,
+                day: 7,
+                part: 2,
+                input: Some(PathBuf::from("-"))
+            },
+            parse_args(&args("submit 7 2 -i -")).unwrap()
+        );
+        assert_eq!(
+            Command::Submit {
+                year: Some(2021),
+                day: 7,
+                part: 2,
+                input: None
+            },
+            parse_args(&args("submit 2021 7 2")).unwrap()
+        );
+        assert_eq!(
+            Command::Gen {
+                day: 22,
+                size: 100,
+                seed: 7,
+                output: None
+            },
+            parse_args(&args("gen 22 --size 100 --seed 7")).unwrap()
+        );
+        assert_eq!(
+            Command::Cache(CacheCommand::Clear {
+                year: None,
+                day: Some(19)
+            }),
+            parse_args(&args("cache clear 19")).unwrap()
+        );
+        assert_eq!(
+            Command::Cache(CacheCommand::Clear {
+                year: Some(2021),
+                day: None
+            }),
+            parse_args(&args("cache clear 2021")).unwrap()
+        );
+        assert_eq!(
+            Command::Cache(CacheCommand::Clear {
+                year: None,
+                day: None
+            }),
+            parse_args(&args("cache clear")).unwrap()
+        );
+        assert_eq!(
+            Command::Cache(CacheCommand::List),
+            parse_args(&args("cache list")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_year() {
+        assert_eq!(
+            Command::Run {
+                year: Some(2021),
+                day: Some(19),
+                part: None,
+                input: None,
+                options: runner::Options::default(),
+                format: Format::Text,
+                cache: true
+            },
+            parse_args(&args("run 2021 19")).unwrap()
+        );
+        assert!(matches!(
+            parse_args(&args("run 2021 --all")).unwrap(),
+            Command::Run {
+                year: Some(2021),
+                day: None,
+                ..
+            }
+        ));
+        assert!(matches!(
+            parse_args(&args("bench 2021")).unwrap(),
+            Command::Bench {
+                year: Some(2021),
+                day: None,
+                ..
+            }
+        ));
+        assert!(parse_args(&args("run 2021")).is_err());
+        assert!(parse_args(&args("run 19 2021")).is_err());
+        assert!(parse_args(&args("run 2021 2020 19")).is_err());
+        assert!(parse_args(&args("run 1999 19")).is_err());
+        assert!(parse_args(&args("submit 1999 7 2")).is_err());
+    }
+
+    #[test]
+    fn test_parse_verify() {
+        assert_eq!(
+            Command::Verify {
+                year: None,
+                day: None,
+                bless: false
+            },
+            parse_args(&args("verify")).unwrap()
+        );
+        assert_eq!(
+            Command::Verify {
+                year: None,
+                day: Some(7),
+                bless: true
+            },
+            parse_args(&args("verify 7 --bless")).unwrap()
+        );
+        assert!(parse_args(&args("verify 7 8")).is_err());
+        assert!(parse_args(&args("verify --part 1")).is_err());
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(parse_args(&args("run")).is_err());
+        assert!(parse_args(&args("run 26")).is_err());
+        assert!(parse_args(&args("new")).is_err());
+        assert!(parse_args(&args("new 4 5")).is_err());
+        assert!(parse_args(&args("submit 7")).is_err());
+        assert!(parse_args(&args("submit 7 3")).is_err());
+        assert!(parse_args(&args("run 3 --part 3")).is_err());
+        assert!(parse_args(&args("run 3 --all")).is_err());
+        assert!(parse_args(&args("run --all --part 1")).is_err());
+        assert!(parse_args(&args("run --all --timeout 0")).is_err());
+        assert!(parse_args(&args("run --all -j 0")).is_err());
+        assert!(parse_args(&args("run --all --format xml")).is_err());
+        assert!(parse_args(&args("gen")).is_err());
+        assert!(parse_args(&args("cache")).is_err());
+        assert!(parse_args(&args("cache clear 19 20")).is_err());
+        assert!(parse_args(&args("cache list 19")).is_err());
+        assert!(parse_args(&args("gen 22 --size")).is_err());
+        assert!(parse_args(&args("frobnicate")).is_err());
+    }
+
+    #[test]
+    fn test_parse_logging() {
+        let (logging, rest) = parse_logging(&args("-vv run 16 --trace 16 --trace runner")).unwrap();
+        assert_eq!(
+            Logging {
+                level: Some(Level::Debug),
+                targets: vec![
+                    ("day16".to_string(), Level::Trace),
+                    ("runner".to_string(), Level::Trace)
+                ]
+            },
+            logging
+        );
+        assert_eq!(args("run 16"), rest);
+        assert_eq!(
+            Logging::default(),
+            parse_logging(&args("run --all")).unwrap().0
+        );
+        assert!(parse_logging(&args("run 16 --trace")).is_err());
+        assert!(parse_logging(&args("run 16 --trace 26")).is_err());
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        assert_eq!(
+            Command::Bench {
+                year: None,
+                day: Some(19),
+                part: Some(1),
+                options: Options {
+                    warmup: 1,
+                    iterations: 20
+                },
+                output: Some(PathBuf::from("out.csv")),
+                baseline: Some(PathBuf::from("old.json")),
+                threshold: 5.0
+            },
+            parse_args(&args(
+                "bench 19 -p 1 --warmup 1 -n 20 -o out.csv --baseline old.json --threshold 5"
+            ))
+            .unwrap()
+        );
+        assert_eq!(
+            Command::Bench {
+                year: None,
+                day: None,
+                part: None,
+                options: Options::default(),
+                output: None,
+                baseline: None,
+                threshold: 10.0
+            },
+            parse_args(&args("bench")).unwrap()
+        );
+        assert!(parse_args(&args("bench --part 1")).is_err());
+        assert!(parse_args(&args("bench 1 --iterations 0")).is_err());
+        assert!(parse_args(&args("bench 1 --threshold x")).is_err());
+        assert!(parse_args(&args("bench 1 --warmup")).is_err());
+    }
+
+    #[test]
+    fn test_parse_viz() {
+        assert_eq!(
+            Command::Viz {
+                year: None,
+                day: 11,
+                input: Some(PathBuf::from("-")),
+                options: viz::Options {
+                    fps: 2.5,
+                    frames: 40,
+                    plain: true
+                }
+            },
+            parse_args(&args("viz 11 -i - --fps 2.5 --frames 40 --plain")).unwrap()
+        );
+        assert_eq!(
+            Command::Viz {
+                year: Some(2021),
+                day: 25,
+                input: None,
+                options: viz::Options::default()
+            },
+            parse_args(&args("viz 2021 25")).unwrap()
+        );
+        assert!(parse_args(&args("viz")).is_err());
+        assert!(parse_args(&args("viz 11 --fps 0")).is_err());
+        assert!(parse_args(&args("viz 11 --frames")).is_err());
+    }
+}
@@ -0,0 +1,275 @@
+use crate::gen::{Generator, Rng};
+use crate::Day;
+use itertools::Itertools;
+use std::fmt::{Debug, Display, Formatter};
+use std::panic::{self, AssertUnwindSafe};
+
+// runs a property on generated cases and shrinks the first failing one
+#[derive(Clone, Debug)]
+pub struct Checker {
+    pub cases: usize,
+    pub seed: u64,
+    pub max_shrinks: usize,
+}
+
+impl Default for Checker {
+    fn default() -> Self {
+        Checker {
+            cases: 200,
+            seed: 0,
+            max_shrinks: 1000,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Failure<T> {
+    // the seed that generates `original` again
+    pub seed: u64,
+    pub original: T,
+    pub shrunk: T,
+    pub shrinks: usize,
+    pub message: String,
+}
+
+impl<T: Debug> Display for Failure<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "property failed for seed {}: {}",
+            self.seed, self.message
+        )?;
+        writeln!(f, "  original: {:?}", self.original)?;
+        write!(f, "  shrunk in {} steps: {:?}", self.shrinks, self.shrunk)
+    }
+}
+
+fn run<T>(property: &impl Fn(&T) -> Result<(), String>, x: &T) -> Result<(), String> {
+    match panic::catch_unwind(AssertUnwindSafe(|| property(x))) {
+        Ok(result) => result,
+        Err(e) => Err(
+            match (e.downcast_ref::<&str>(), e.downcast_ref::<String>()) {
+                (Some(s), _) => format!("panicked: {}", s),
+                (_, Some(s)) => format!("panicked: {}", s),
+                _ => "panicked".to_string(),
+            },
+        ),
+    }
+}
+
+impl Checker {
+    pub fn check<T: Clone + Debug>(
+        &self,
+        generate: impl Fn(&mut Rng) -> T,
+        shrink: impl Fn(&T) -> Vec<T>,
+        property: impl Fn(&T) -> Result<(), String>,
+    ) -> Result<(), Failure<T>> {
+        for case in 0..self.cases as u64 {
+            let seed = self.seed.wrapping_add(case);
+            let original = generate(&mut Rng::new(seed));
+            let mut message = match run(&property, &original) {
+                Ok(()) => continue,
+                Err(message) => message,
+            };
+
+            // greedily take the first smaller case that still fails
+            let mut shrunk = original.clone();
+            let mut shrinks = 0;
+            'shrink: while shrinks < self.max_shrinks {
+                for candidate in shrink(&shrunk) {
+                    if let Err(m) = run(&property, &candidate) {
+                        shrunk = candidate;
+                        message = m;
+                        shrinks += 1;
+                        continue 'shrink;
+                    }
+                }
+                break;
+            }
+
+            return Err(Failure {
+                seed,
+                original,
+                shrunk,
+                shrinks,
+                message,
+            });
+        }
+        Ok(())
+    }
+
+    // checks that a fast implementation agrees with a simple reference implementation
+    pub fn compare<T: Clone + Debug, R: PartialEq + Debug>(
+        &self,
+        generate: impl Fn(&mut Rng) -> T,
+        shrink: impl Fn(&T) -> Vec<T>,
+        fast: impl Fn(&T) -> R,
+        reference: impl Fn(&T) -> R,
+    ) -> Result<(), Failure<T>> {
+        self.check(generate, shrink, |x| {
+            let (a, b) = (fast(x), reference(x));
+            match a == b {
+                true => Ok(()),
+                false => Err(format!("fast {:?} != reference {:?}", a, b)),
+            }
+        })
+    }
+
+    // compares two solutions of a day on inputs of its generator with up to `max_size` lines
+    // after up to `max_steps` steps, and panics with the shrunk input if they disagree
+    pub fn assert_agree<D: Day, R: PartialEq + Debug>(
+        &self,
+        generator: Generator,
+        max_size: usize,
+        max_steps: usize,
+        fast: impl Fn(&D::Input, usize) -> R,
+        reference: impl Fn(&D::Input, usize) -> R,
+    ) {
+        self.check(
+            |rng| {
+                let size = 1 + rng.below(max_size);
+                (generator(rng, size), rng.below(max_steps + 1))
+            },
+            |x| {
+                let shrink_steps = |&x: &usize| {
+                    shrink_int(x as i64)
+                        .into_iter()
+                        .map(|x| x as usize)
+                        .collect()
+                };
+                shrink_pair(x, |input| shrink_input(input), shrink_steps)
+            },
+            |(input, steps)| {
+                // shrinking may leave an input the day rejects, which is no counterexample
+                let input = match D::parse(input) {
+                    Ok(x) => x,
+                    Err(_) => return Ok(()),
+                };
+                let (a, b) = (fast(&input, *steps), reference(&input, *steps));
+                match a == b {
+                    true => Ok(()),
+                    false => Err(format!("fast {:?} != reference {:?}", a, b)),
+                }
+            },
+        )
+        .unwrap_or_else(|failure| panic!("{}", failure));
+    }
+}
+
+// drops lines, or the comma separated items of an input on a single line
+pub fn shrink_input(input: &str) -> Vec<String> {
+    let (items, separator) = match input.contains('\n') {
+        true => (input.lines().collect_vec(), "\n"),
+        false => (input.split(',').collect_vec(), ","),
+    };
+    shrink_vec(&items, |_| vec![])
+        .into_iter()
+        .filter(|xs| !xs.is_empty())
+        .map(|xs| xs.join(separator))
+        .collect()
+}
+
+// smaller integers, moving towards 0
+pub fn shrink_int(x: i64) -> Vec<i64> {
+    let mut xs = vec![0, x / 2, x - x.signum()];
+    xs.dedup();
+    xs.retain(|&y| y != x);
+    xs
+}
+
+// shorter vectors first, then vectors with a single element shrunk
+pub fn shrink_vec<T: Clone>(xs: &[T], shrink: impl Fn(&T) -> Vec<T>) -> Vec<Vec<T>> {
+    let mut out = vec![];
+    if xs.len() > 1 {
+        out.push(xs[..xs.len() / 2].to_vec());
+        out.push(xs[xs.len() / 2..].to_vec());
+    }
+    for i in 0..xs.len() {
+        let mut ys = xs.to_vec();
+        ys.remove(i);
+        out.push(ys);
+    }
+    for (i, x) in xs.iter().enumerate() {
+        for y in shrink(x) {
+            let mut ys = xs.to_vec();
+            ys[i] = y;
+            out.push(ys);
+        }
+    }
+    out
+}
+
+// shrinks one side of the pair at a time
+pub fn shrink_pair<A: Clone, B: Clone>(
+    (a, b): &(A, B),
+    shrink_a: impl Fn(&A) -> Vec<A>,
+    shrink_b: impl Fn(&B) -> Vec<B>,
+) -> Vec<(A, B)> {
+    let xs = shrink_a(a).into_iter().map(|a| (a, b.clone()));
+    let ys = shrink_b(b).into_iter().map(|b| (a.clone(), b));
+    xs.chain(ys).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gen::check::{shrink_input, shrink_int, shrink_vec, Checker};
+    use crate::gen::lanternfish;
+    use crate::y2021::day6::Day6;
+
+    #[test]
+    fn test_shrink() {
+        let failure = Checker::default()
+            .check(
+                |rng| (0..10).map(|_| rng.range(0..100)).collect::<Vec<_>>(),
+                |xs| shrink_vec(xs, |&x| shrink_int(x)),
+                |xs| match xs.iter().all(|&x| x < 50) {
+                    true => Ok(()),
+                    false => Err("too large".to_string()),
+                },
+            )
+            .unwrap_err();
+        assert_eq!(vec![50], failure.shrunk);
+        assert!(failure.original.len() == 10);
+        assert!(failure.to_string().contains("shrunk"));
+
+        assert_eq!(vec![0, -3, -6], shrink_int(-7));
+        assert!(shrink_int(0).is_empty());
+    }
+
+    #[test]
+    fn test_compare() {
+        let checker = Checker::default();
+        let sum = |xs: &Vec<i64>| xs.iter().sum::<i64>();
+        let generate = |rng: &mut crate::gen::Rng| (0..5).map(|_| rng.range(-9..10)).collect();
+        let shrink = |xs: &Vec<i64>| shrink_vec(xs, |&x| shrink_int(x));
+        assert!(checker
+            .compare(generate, shrink, sum, |xs| xs.iter().rev().sum())
+            .is_ok());
+
+        // panics are failures as well and are shrunk like any other
+        let failure = checker
+            .compare(generate, shrink, sum, |xs| xs.iter().map(|x| 10 / x).sum())
+            .unwrap_err();
+        assert_eq!(vec![0], failure.shrunk);
+        assert!(failure.message.starts_with("panicked"));
+    }
+
+    #[test]
+    fn test_shrink_input() {
+        assert_eq!(vec!["1", "2,3", "2,3", "1,3", "1,2"], shrink_input("1,2,3"));
+        assert_eq!(vec!["a", "b", "b", "a"], shrink_input("a\nb"));
+        assert!(shrink_input("1").is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "property failed")]
+    fn test_assert_agree() {
+        Checker::default().assert_agree::<Day6, _>(
+            lanternfish,
+            8,
+            0,
+            |fish, _| fish.len(),
+            |fish, _| fish.len().min(3),
+        );
+    }
+}
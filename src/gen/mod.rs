@@ -0,0 +1,350 @@
+pub mod check;
+mod rng;
+
+use anyhow::{bail, Result};
+use itertools::Itertools;
+
+pub use rng::Rng;
+
+// produces a random valid input, `size` is the number of lines, cells per side,
+// packets or elements
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+pub fn generators() -> Vec<(u32, Generator)> {
+    vec![
+        (5, line_segments),
+        (6, lanternfish),
+        (9, digit_grid),
+        (11, digit_grid),
+        (12, cave_graph),
+        (14, polymer),
+        (15, risk_grid),
+        (16, transmission),
+        (18, snailfish_numbers),
+        (22, reboot_steps),
+    ]
+}
+
+pub fn generator(day: u32) -> Option<Generator> {
+    generators()
+        .into_iter()
+        .find(|(d, _)| *d == day)
+        .map(|(_, g)| g)
+}
+
+pub fn generate(day: u32, size: usize, seed: u64) -> Result<String> {
+    match generator(day) {
+        Some(g) => Ok(g(&mut Rng::new(seed), size)),
+        None => bail!(
+            "No generator for day {}, available are days {}",
+            day,
+            generators().iter().map(|(d, _)| d).join(", ")
+        ),
+    }
+}
+
+fn grid(rng: &mut Rng, size: usize, digits: std::ops::Range<i64>) -> String {
+    (0..size.max(1))
+        .map(|_| (0..size.max(1)).map(|_| rng.range(digits.clone())).join(""))
+        .join("\n")
+}
+
+pub fn digit_grid(rng: &mut Rng, size: usize) -> String {
+    grid(rng, size, 0..10)
+}
+
+pub fn risk_grid(rng: &mut Rng, size: usize) -> String {
+    grid(rng, size, 1..10)
+}
+
+// the timers of `size` fish
+pub fn lanternfish(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1)).map(|_| rng.below(9)).join(",")
+}
+
+// horizontal, vertical and diagonal vents
+pub fn line_segments(rng: &mut Rng, size: usize) -> String {
+    let extent = 10 + size as i64;
+    let dirs = [
+        (1, 0),
+        (0, 1),
+        (1, 1),
+        (1, -1),
+        (-1, 0),
+        (0, -1),
+        (-1, -1),
+        (-1, 1),
+    ];
+    (0..size.max(1))
+        .map(|_| {
+            let (x, y) = (rng.range(0..extent), rng.range(0..extent));
+            let (dx, dy) = *rng.choose(&dirs);
+            let room = |p: i64, d: i64| match d {
+                1 => extent - 1 - p,
+                -1 => p,
+                _ => extent,
+            };
+            let len = rng.range(0..room(x, dx).min(room(y, dy)) + 1);
+            format!("{},{} -> {},{}", x, y, x + dx * len, y + dy * len)
+        })
+        .join("\n")
+}
+
+// `i` in base 26 with at least two letters, "aa", "ab", ..., "zz", "baa", ...
+fn cave_name(mut i: usize) -> String {
+    let mut s = vec![];
+    while i > 0 || s.len() < 2 {
+        s.push((b'a' + (i % 26) as u8) as char);
+        i /= 26;
+    }
+    s.iter().rev().collect()
+}
+
+// big caves are never connected to each other, otherwise there are infinitely many paths
+pub fn cave_graph(rng: &mut Rng, size: usize) -> String {
+    let names = (0..)
+        .map(cave_name)
+        .filter(|x| x != "start" && x != "end")
+        .take(size.max(1))
+        .collect_vec();
+    let mut caves = vec![("start".to_string(), false)];
+    for name in names {
+        let big = rng.chance(0.3);
+        caves.push((if big { name.to_uppercase() } else { name }, big));
+    }
+    caves.push(("end".to_string(), false));
+
+    let mut edges: Vec<(usize, usize)> = vec![];
+    for i in 1..caves.len() {
+        let candidates = (0..i)
+            .filter(|&j| !(caves[i].1 && caves[j].1))
+            .collect_vec();
+        edges.push((*rng.choose(&candidates), i));
+    }
+    for _ in 0..size / 2 {
+        let (a, b) = (rng.below(caves.len()), rng.below(caves.len()));
+        let (a, b) = (a.min(b), a.max(b));
+        if a != b && !(caves[a].1 && caves[b].1) && !edges.contains(&(a, b)) {
+            edges.push((a, b));
+        }
+    }
+
+    edges
+        .iter()
+        .map(|&(a, b)| match rng.chance(0.5) {
+            true => format!("{}-{}", caves[a].0, caves[b].0),
+            false => format!("{}-{}", caves[b].0, caves[a].0),
+        })
+        .join("\n")
+}
+
+// a template of `size` elements and rules for most of their pairs
+pub fn polymer(rng: &mut Rng, size: usize) -> String {
+    let elements = &['B', 'C', 'H', 'N'][..1 + rng.below(4)];
+    let template: String = (0..size.max(2)).map(|_| *rng.choose(elements)).collect();
+    let rules = elements
+        .iter()
+        .cartesian_product(elements)
+        .filter_map(|(a, b)| match rng.chance(0.8) {
+            true => Some(format!("{}{} -> {}", a, b, rng.choose(elements))),
+            false => None,
+        })
+        .collect_vec();
+    format!("{}\n\n{}", template, rules.join("\n"))
+}
+
+fn push_bits(s: &mut String, value: u64, n: usize) {
+    for i in (0..n).rev() {
+        s.push(if value >> i & 1 == 1 { '1' } else { '0' });
+    }
+}
+
+fn literal(rng: &mut Rng, max: u64) -> String {
+    let mut s = String::new();
+    push_bits(&mut s, rng.below(8) as u64, 3);
+    push_bits(&mut s, 4, 3);
+    let value = rng.next_u64() % max;
+    let groups = (64 - value.leading_zeros() as usize).div_ceil(4);
+    for i in (0..groups.max(1)).rev() {
+        push_bits(&mut s, (i > 0) as u64, 1);
+        push_bits(&mut s, value >> (4 * i) & 0xf, 4);
+    }
+    s
+}
+
+// products only get small literals, so that evaluating the packet does not overflow
+fn packet(rng: &mut Rng, budget: &mut usize, depth: usize) -> String {
+    if *budget <= 1 || depth >= 6 || rng.chance(0.3) {
+        *budget = budget.saturating_sub(1);
+        return literal(rng, 1 << 20);
+    }
+    *budget -= 1;
+
+    let typ = *rng.choose(&[0, 1, 2, 3, 5, 6, 7]);
+    let n = match typ {
+        5..=7 => 2,
+        _ => 1 + rng.below(3),
+    };
+    let children = (0..n)
+        .map(|_| match typ {
+            1 => literal(rng, 16),
+            _ => packet(rng, budget, depth + 1),
+        })
+        .collect_vec();
+
+    let mut s = String::new();
+    push_bits(&mut s, rng.below(8) as u64, 3);
+    push_bits(&mut s, typ, 3);
+    let body = children.concat();
+    if rng.chance(0.5) && body.len() < 1 << 15 {
+        push_bits(&mut s, 0, 1);
+        push_bits(&mut s, body.len() as u64, 15);
+    } else {
+        push_bits(&mut s, 1, 1);
+        push_bits(&mut s, children.len() as u64, 11);
+    }
+    s + &body
+}
+
+// a BITS transmission of about `size` packets
+pub fn transmission(rng: &mut Rng, size: usize) -> String {
+    let bits = packet(rng, &mut size.max(1), 0);
+    let bits = format!("{:0<width$}", bits, width = bits.len().div_ceil(4) * 4);
+    bits.as_bytes()
+        .chunks(4)
+        .map(|c| {
+            let nibble = c.iter().fold(0, |acc, b| acc * 2 + (b - b'0') as u32);
+            std::char::from_digit(nibble, 16)
+                .unwrap()
+                .to_ascii_uppercase()
+        })
+        .collect()
+}
+
+// reduced numbers, no pair is nested inside four pairs
+fn snailfish(rng: &mut Rng, depth: usize) -> String {
+    if depth <= 4 && rng.chance(0.5) {
+        format!(
+            "[{},{}]",
+            snailfish(rng, depth + 1),
+            snailfish(rng, depth + 1)
+        )
+    } else {
+        rng.below(10).to_string()
+    }
+}
+
+pub fn snailfish_numbers(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| format!("[{},{}]", snailfish(rng, 2), snailfish(rng, 2)))
+        .join("\n")
+}
+
+// mostly steps in the -50..=50 initialization region, some far outside of it
+pub fn reboot_steps(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|i| {
+            let (limit, span) = match rng.chance(0.8) {
+                true => (50, 30),
+                false => (100_000, 50_000),
+            };
+            let mut range = || {
+                let a = rng.range(-limit..limit + 1);
+                let b = (a + rng.range(0..span)).min(limit);
+                format!("{}..{}", a, b)
+            };
+            let (x, y, z) = (range(), range(), range());
+            let on = i == 0 || rng.chance(0.6);
+            format!("{} x={},y={},z={}", if on { "on" } else { "off" }, x, y, z)
+        })
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gen::{cave_graph, cave_name, generate, generators, transmission, Rng};
+    use crate::registry;
+    use crate::y2021::{day11, day12, day14, day15, day16, day18, day22, day5, day6, day9, YEAR};
+    use crate::Day;
+    use itertools::Itertools;
+    use std::collections::HashSet;
+    use std::panic::{self, AssertUnwindSafe};
+
+    fn parses(day: u32, input: &str) -> bool {
+        match day {
+            5 => day5::Day5::parse(input).is_ok(),
+            6 => day6::Day6::parse(input).is_ok(),
+            9 => day9::Day9::parse(input).is_ok(),
+            11 => day11::Day11::parse(input).is_ok(),
+            12 => day12::Day12::parse(input).is_ok(),
+            14 => day14::Day14::parse(input).is_ok(),
+            15 => day15::Day15::parse(input).is_ok(),
+            16 => day16::Day16::parse(input).is_ok(),
+            18 => day18::Day18::parse(input).is_ok(),
+            22 => day22::Day22::parse(input).is_ok(),
+            _ => false,
+        }
+    }
+
+    #[test]
+    fn test_generators() {
+        for (day, _) in generators() {
+            for seed in 0..20 {
+                let input = generate(day, 1 + seed as usize, seed).unwrap();
+                assert!(parses(day, &input), "day {} seed {}:\n{}", day, seed, input);
+                assert_eq!(input, generate(day, 1 + seed as usize, seed).unwrap());
+            }
+        }
+        assert_eq!(5, generate(9, 5, 1).unwrap().lines().count());
+        assert_eq!(3, generate(22, 3, 1).unwrap().lines().count());
+        assert!(generate(1, 5, 1).is_err());
+    }
+
+    #[test]
+    fn test_solutions() {
+        // the solutions may reject an input, e.g. octopuses that never flash together, but not
+        // panic on it
+        for (day, _) in generators() {
+            for part in [1, 2] {
+                let solution = registry::find(YEAR, day, part).unwrap().solution;
+                for seed in 0..10 {
+                    let input = generate(day, 1 + seed as usize % 6, seed).unwrap();
+                    let result = panic::catch_unwind(AssertUnwindSafe(|| solution(&input)));
+                    assert!(
+                        result.is_ok(),
+                        "day {} part {} seed {}:\n{}",
+                        day,
+                        part,
+                        seed,
+                        input
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_cave_names() {
+        assert_eq!(
+            vec!["aa", "ab", "zz", "baa", "zzz", "baaa"],
+            [0, 1, 675, 676, 17575, 17576].map(cave_name)
+        );
+
+        let input = cave_graph(&mut Rng::new(1), 2000);
+        let caves: HashSet<&str> = input.split(['\n', '-']).collect();
+        assert_eq!(2002, caves.len());
+        assert!(day12::Day12::parse(&input).is_ok());
+    }
+
+    #[test]
+    fn test_transmission() {
+        let input = transmission(&mut Rng::new(3), 1);
+        assert_eq!(1, day16::Day16::parse(&input).unwrap().len());
+
+        let solved = (0..20)
+            .map(|seed| transmission(&mut Rng::new(seed), 30))
+            .map(|input| day16::Day16::solution1(&input))
+            .collect_vec();
+        assert!(solved.iter().all(|x| x.is_ok()));
+    }
+}
@@ -0,0 +1,57 @@
+use std::ops::Range;
+
+// SplitMix64, small and good enough for generating puzzle inputs
+#[derive(Clone, Debug)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    // a number in 0..n, n must not be 0
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "empty range");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    pub fn range(&mut self, r: Range<i64>) -> i64 {
+        assert!(r.start < r.end, "empty range {:?}", r);
+        r.start + (self.next_u64() % (r.end - r.start) as u64) as i64
+    }
+
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, xs: &'a [T]) -> &'a T {
+        &xs[self.below(xs.len())]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gen::Rng;
+
+    #[test]
+    fn test_rng() {
+        let xs = (0..5).map(|_| Rng::new(7).next_u64()).collect::<Vec<_>>();
+        assert!(xs.iter().all(|&x| x == xs[0]));
+        assert_ne!(Rng::new(7).next_u64(), Rng::new(8).next_u64());
+
+        let mut rng = Rng::new(1);
+        let rolls = (0..1000).map(|_| rng.range(-3..4)).collect::<Vec<_>>();
+        assert!(rolls.iter().all(|x| (-3..4).contains(x)));
+        assert!((-3..4).all(|x| rolls.contains(&x)));
+        assert!(!rng.chance(0.0));
+        assert!(rng.chance(1.0));
+    }
+}
@@ -0,0 +1,359 @@
+use anyhow::{bail, Context, Result};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
+pub const HASHES_FILE: &str = "SHA256SUMS";
+pub const USER_AGENT: &str = "github.com/lukstei/AdventOfCode2021";
+
+pub trait Downloader {
+    fn download(&self, year: u32, day: u32) -> Result<String>;
+}
+
+// fetches `<url>/<year>/day/<day>/input` with the session cookie of a logged in user
+pub struct HttpDownloader {
+    pub url: String,
+    pub session: String,
+}
+
+impl Downloader for HttpDownloader {
+    fn download(&self, year: u32, day: u32) -> Result<String> {
+        let url = format!(
+            "{}/{}/day/{}/input",
+            self.url.trim_end_matches('/'),
+            year,
+            day
+        );
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .call();
+
+        match response {
+            Ok(r) => Ok(r.into_string()?),
+            Err(ureq::Error::Status(code, _)) => bail!("{} returned status {}", url, code),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Source {
+    Cache,
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    // `-` reads from stdin
+    pub fn from_arg(input: &Option<PathBuf>) -> Source {
+        match input {
+            None => Source::Cache,
+            Some(path) if path.as_os_str() == "-" => Source::Stdin,
+            Some(path) => Source::File(path.clone()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Input {
+    pub name: String,
+    pub text: String,
+    pub hash: String,
+}
+
+impl Input {
+    // normalises line endings to a single trailing "\n"
+    pub fn new(name: &str, raw: &str) -> Result<Input> {
+        let mut text = raw.replace("\r\n", "\n").trim_end().to_string();
+        if text.trim().is_empty() {
+            bail!("Input {} is empty", name);
+        }
+        text.push('\n');
+
+        Ok(Input {
+            name: name.to_string(),
+            hash: hash(&text),
+            text,
+        })
+    }
+
+    // what the solutions get to see
+    pub fn trimmed(&self) -> &str {
+        self.text.trim()
+    }
+}
+
+pub fn hash(s: &str) -> String {
+    Sha256::digest(s.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+// AOC_URL points the client at another server, e.g. a local stand-in
+pub fn url_from_env() -> String {
+    std::env::var("AOC_URL").unwrap_or_else(|_| DEFAULT_URL.to_string())
+}
+
+pub fn session_from_env() -> Option<String> {
+    let session = std::env::var("AOC_SESSION").ok()?;
+    Some(session.trim().to_string()).filter(|x| !x.is_empty())
+}
+
+// every year has its own folder, e.g. `2021/day19.txt`
+pub fn file_name(year: u32, day: u32) -> String {
+    format!("{}/day{}.txt", year, day)
+}
+
+pub struct Inputs {
+    dir: PathBuf,
+    downloader: Option<Box<dyn Downloader>>,
+}
+
+impl Inputs {
+    pub fn new(dir: impl Into<PathBuf>) -> Inputs {
+        Inputs {
+            dir: dir.into(),
+            downloader: None,
+        }
+    }
+
+    // downloads are enabled by setting AOC_SESSION
+    pub fn from_env() -> Inputs {
+        let inputs = Inputs::new("inputs");
+        match session_from_env() {
+            Some(session) => inputs.with_downloader(HttpDownloader {
+                url: url_from_env(),
+                session,
+            }),
+            None => inputs,
+        }
+    }
+
+    pub fn with_downloader(mut self, downloader: impl Downloader + 'static) -> Inputs {
+        self.downloader = Some(Box::new(downloader));
+        self
+    }
+
+    pub fn path(&self, year: u32, day: u32) -> PathBuf {
+        self.dir.join(file_name(year, day))
+    }
+
+    pub fn resolve(&self, year: u32, day: u32, source: &Source) -> Result<Input> {
+        match source {
+            Source::Cache => self.get(year, day),
+            Source::File(path) => Input::new(&path.display().to_string(), &read(path)?),
+            Source::Stdin => {
+                let mut s = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut s)
+                    .context("Unable to read input from stdin")?;
+                Input::new("<stdin>", &s)
+            }
+        }
+    }
+
+    // reads the cached input, downloading it first if missing or still blank as `aoc new`
+    // leaves it
+    pub fn get(&self, year: u32, day: u32) -> Result<Input> {
+        let path = self.path(year, day);
+        let cached = path.exists() && !read(&path)?.trim().is_empty();
+        if cached {
+            return self.read(&file_name(year, day));
+        }
+
+        let downloader = match &self.downloader {
+            Some(x) => x,
+            None if path.exists() => bail!(
+                "{} is empty, set AOC_SESSION to download it",
+                path.display()
+            ),
+            None => bail!(
+                "{} does not exist, set AOC_SESSION to download it",
+                path.display()
+            ),
+        };
+        let raw = downloader
+            .download(year, day)
+            .with_context(|| format!("Unable to download the input of {} day {}", year, day))?;
+        let input = Input::new(&path.display().to_string(), &raw)?;
+
+        fs::create_dir_all(path.parent().unwrap_or(&self.dir))?;
+        fs::write(&path, &input.text)
+            .with_context(|| format!("Unable to write {}", path.display()))?;
+        self.record_hash(&file_name(year, day), &input.hash)?;
+        Ok(input)
+    }
+
+    // reads a cached input, checking it against its recorded hash
+    pub fn read(&self, name: &str) -> Result<Input> {
+        let path = self.dir.join(name);
+        let input = Input::new(&path.display().to_string(), &read(&path)?)?;
+        match self.hashes()?.get(name) {
+            Some(h) if *h != input.hash => bail!(
+                "{} has changed since it was downloaded, its hash in {} is {}",
+                path.display(),
+                self.dir.join(HASHES_FILE).display(),
+                h
+            ),
+            _ => Ok(input),
+        }
+    }
+
+    // in the format of sha256sum, "<hash>  <file>"
+    fn hashes(&self) -> Result<BTreeMap<String, String>> {
+        let path = self.dir.join(HASHES_FILE);
+        if !path.exists() {
+            return Ok(Default::default());
+        }
+        Ok(read(&path)?
+            .lines()
+            .filter_map(|l| l.split_once("  "))
+            .map(|(h, name)| (name.to_string(), h.to_string()))
+            .collect())
+    }
+
+    fn record_hash(&self, name: &str, hash: &str) -> Result<()> {
+        let mut hashes = self.hashes()?;
+        hashes.insert(name.to_string(), hash.to_string());
+        let s: String = hashes
+            .iter()
+            .map(|(name, h)| format!("{}  {}\n", h, name))
+            .collect();
+        let path = self.dir.join(HASHES_FILE);
+        fs::write(&path, s).with_context(|| format!("Unable to write {}", path.display()))
+    }
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("Unable to read input {}", path.display()))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::inputs::{hash, Downloader, HttpDownloader, Input, Inputs, Source, HASHES_FILE};
+    use std::cell::Cell;
+    use std::fs;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::path::PathBuf;
+    use std::rc::Rc;
+    use std::thread;
+
+    // answers a single request, returns the url and the request including its body
+    pub fn mock_server(
+        status: &'static str,
+        body: &'static str,
+    ) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = String::new();
+            let mut buf = [0; 1024];
+            loop {
+                let n = stream.read(&mut buf).unwrap();
+                request += std::str::from_utf8(&buf[..n]).unwrap();
+                if let Some((headers, body)) = request.split_once("\r\n\r\n") {
+                    let len = headers
+                        .lines()
+                        .filter_map(|l| l.split_once(": "))
+                        .find(|(k, _)| k.eq_ignore_ascii_case("content-length"))
+                        .map_or(0, |(_, v)| v.parse().unwrap());
+                    if body.len() >= len {
+                        break;
+                    }
+                }
+            }
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+            request
+        });
+        (url, handle)
+    }
+
+    pub struct Counting(pub Rc<Cell<usize>>);
+
+    impl Downloader for Counting {
+        fn download(&self, year: u32, day: u32) -> anyhow::Result<String> {
+            self.0.set(self.0.get() + 1);
+            Ok(format!("{}\r\n{}\r\n\r\n", day, year))
+        }
+    }
+
+    pub fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_input() {
+        let input = Input::new("x", "1\r\n2\n\n\n").unwrap();
+        assert_eq!("1\n2\n", input.text);
+        assert_eq!("1\n2", input.trimmed());
+        assert_eq!(hash("1\n2\n"), input.hash);
+        assert_eq!(64, input.hash.len());
+        assert!(Input::new("x", " \n\n").is_err());
+        assert_eq!(Source::Stdin, Source::from_arg(&Some(PathBuf::from("-"))));
+    }
+
+    #[test]
+    fn test_http_downloader() {
+        let (url, server) = mock_server("200 OK", "1\n2\n");
+        let downloader = HttpDownloader {
+            url,
+            session: "secret".to_string(),
+        };
+        assert_eq!("1\n2\n", downloader.download(2020, 7).unwrap());
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2020/day/7/input HTTP/1.1\r\n"));
+        assert!(request.contains("\r\nCookie: session=secret\r\n"));
+
+        let (url, server) = mock_server("400 Bad Request", "Please log in");
+        let downloader = HttpDownloader {
+            url,
+            session: "expired".to_string(),
+        };
+        let e = downloader.download(2021, 7).unwrap_err();
+        assert!(e.to_string().ends_with("returned status 400"));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_cache() {
+        let dir = temp_dir("inputs");
+        let count = Rc::new(Cell::new(0));
+        let inputs = Inputs::new(&dir).with_downloader(Counting(count.clone()));
+
+        let input = inputs.get(2021, 3).unwrap();
+        assert_eq!("3\n2021", input.trimmed());
+        let path = dir.join("2021").join("day3.txt");
+        assert_eq!("3\n2021\n", fs::read_to_string(&path).unwrap());
+        assert_eq!(input, inputs.get(2021, 3).unwrap());
+        assert_eq!(1, count.get());
+
+        // the same day of another year is another input
+        assert_eq!("3\n2020", inputs.get(2020, 3).unwrap().trimmed());
+        assert_eq!(2, count.get());
+
+        let hashes = fs::read_to_string(dir.join(HASHES_FILE)).unwrap();
+        assert!(hashes.ends_with(&format!("{}  2021/day3.txt\n", input.hash)));
+
+        fs::write(&path, "changed").unwrap();
+        assert!(inputs.get(2021, 3).is_err());
+        assert!(Inputs::new(&dir).get(2021, 4).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,39 @@
+pub mod answers;
+pub mod bench;
+pub mod cache;
+pub mod gen;
+pub mod inputs;
+pub mod registry;
+pub mod runner;
+pub mod scaffold;
+pub mod submit;
+pub mod template;
+pub mod util;
+pub mod viz;
+pub mod y2021;
+
+pub type Solution = fn(&str) -> anyhow::Result<String>;
+
+pub trait Day {
+    type Input;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input>;
+    fn part1(input: &Self::Input) -> anyhow::Result<String>;
+    fn part2(input: &Self::Input) -> anyhow::Result<String>;
+
+    fn solution1(input: &str) -> anyhow::Result<String> {
+        Self::part1(&Self::parse(input)?)
+    }
+
+    fn solution2(input: &str) -> anyhow::Result<String> {
+        Self::part2(&Self::parse(input)?)
+    }
+}
+
+pub fn run_solution(input_file: &str, solution: Solution) -> anyhow::Result<()> {
+    let input = inputs::Inputs::new("inputs").read(input_file)?;
+
+    let answer = solution(input.trimmed()).map_err(|e| util::name_input(e, input_file))?;
+    println!("Solution with input {}: {}", input_file, answer);
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use crate::bench::Bench;
+use crate::inputs;
+use crate::Solution;
+use itertools::Itertools;
+use std::path::PathBuf;
+
+#[derive(Clone, Copy)]
+pub struct Puzzle {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub solution: Solution,
+    pub bench: Bench,
+}
+
+impl Puzzle {
+    pub fn input_file(&self) -> String {
+        inputs::file_name(self.year, self.day)
+    }
+
+    pub fn input_path(&self) -> PathBuf {
+        PathBuf::from("inputs").join(self.input_file())
+    }
+}
+
+// year modules register their days with `puzzles![YEAR; 1 => day1::Day1, ..]`
+#[macro_export]
+macro_rules! puzzles {
+    ($year:expr; $($day:literal => $module:ident::$ty:ident),* $(,)?) => {
+        vec![$(
+            $crate::registry::Puzzle {
+                year: $year,
+                day: $day,
+                part: 1,
+                solution: <$module::$ty as $crate::Day>::solution1,
+                bench: $crate::bench::measure::<$module::$ty>,
+            },
+            $crate::registry::Puzzle {
+                year: $year,
+                day: $day,
+                part: 2,
+                solution: <$module::$ty as $crate::Day>::solution2,
+                bench: $crate::bench::measure::<$module::$ty>,
+            },
+        )*]
+    };
+}
+
+// the puzzles of every year module
+pub fn puzzles() -> Vec<Puzzle> {
+    [crate::y2021::puzzles()].concat()
+}
+
+pub fn years() -> Vec<u32> {
+    puzzles().iter().map(|p| p.year).unique().sorted().collect()
+}
+
+pub fn latest_year() -> u32 {
+    *years().last().expect("no puzzles registered")
+}
+
+pub fn find(year: u32, day: u32, part: u32) -> Option<Puzzle> {
+    puzzles()
+        .into_iter()
+        .find(|p| p.year == year && p.day == day && p.part == part)
+}
+
+pub fn days(year: u32) -> Vec<u32> {
+    puzzles()
+        .iter()
+        .filter(|p| p.year == year)
+        .map(|p| p.day)
+        .unique()
+        .collect()
+}
+
+pub fn for_day(year: u32, day: u32) -> Vec<Puzzle> {
+    puzzles()
+        .into_iter()
+        .filter(|p| p.year == year && p.day == day)
+        .collect()
+}
+
+pub fn select(year: u32, day: Option<u32>, part: Option<u32>) -> Vec<Puzzle> {
+    puzzles()
+        .into_iter()
+        .filter(|p| p.year == year)
+        .filter(|p| day.is_none_or(|d| p.day == d))
+        .filter(|p| part.is_none_or(|x| p.part == x))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry::{days, find, for_day, latest_year, puzzles, select, years};
+
+    #[test]
+    fn test_all_days_registered() {
+        assert_eq!(50, puzzles().len());
+        assert!((1..=25).all(|day| for_day(2021, day).len() == 2));
+        assert_eq!(vec![2021], years());
+        assert_eq!(25, days(2021).len());
+        assert!(days(2020).is_empty());
+    }
+
+    #[test]
+    fn test_find() {
+        let puzzle = find(2021, 19, 2).unwrap();
+        assert_eq!((2021, 19, 2), (puzzle.year, puzzle.day, puzzle.part));
+        assert_eq!("2021/day19.txt", puzzle.input_file());
+        assert!(find(2021, 26, 1).is_none());
+        assert!(find(2021, 1, 3).is_none());
+        assert!(find(2020, 1, 1).is_none());
+    }
+
+    #[test]
+    fn test_select() {
+        assert_eq!(2021, latest_year());
+        assert_eq!(2, select(2021, Some(19), None).len());
+        assert_eq!(1, select(2021, Some(19), Some(2)).len());
+        assert_eq!(50, select(2021, None, None).len());
+        assert!(select(2020, Some(19), None).is_empty());
+    }
+}
@@ -0,0 +1,390 @@
+use crate::cache::Entry;
+use crate::inputs::Input;
+use crate::registry::Puzzle;
+use crate::util;
+use crate::util::log;
+use serde_json::{json, Value};
+use std::any::Any;
+use std::fmt::{Display, Formatter};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Options {
+    pub timeout: Duration,
+    pub stack_size: usize,
+    pub jobs: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            timeout: Duration::from_secs(60),
+            stack_size: 256 * 1024 * 1024,
+            jobs: thread::available_parallelism().map_or(1, |x| x.get()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Status {
+    Ok(String),
+    Error(String),
+    Panic(String),
+    Timeout,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Ok(answer) => write!(f, "{}", answer),
+            Status::Error(e) => write!(f, "error: {}", e),
+            Status::Panic(e) => write!(f, "panicked: {}", e),
+            Status::Timeout => write!(f, "timed out"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Outcome {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub status: Status,
+    pub elapsed: Duration,
+    // the answer came from the cache, `elapsed` is how long computing it took back then
+    pub cached: bool,
+    // what the solution logged while it ran
+    pub log: Vec<String>,
+}
+
+impl Outcome {
+    pub fn new(puzzle: &Puzzle, status: Status, elapsed: Duration) -> Outcome {
+        Outcome {
+            year: puzzle.year,
+            day: puzzle.day,
+            part: puzzle.part,
+            status,
+            elapsed,
+            cached: false,
+            log: vec![],
+        }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        matches!(self.status, Status::Ok(_))
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(s) => *s,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(s) => s.to_string(),
+            Err(_) => "<unknown panic payload>".to_string(),
+        },
+    }
+}
+
+// runs the solution on its own thread, a solution that does not finish in time is left behind
+pub fn run(puzzle: &Puzzle, input: &Input, options: &Options) -> Outcome {
+    let solution = puzzle.solution;
+    let name = input.name.clone();
+    let text = input.trimmed().to_string();
+    let (sender, receiver) = mpsc::channel();
+
+    let start = Instant::now();
+    let spawned = thread::Builder::new()
+        .name(format!(
+            "{}-day{}-part{}",
+            puzzle.year, puzzle.day, puzzle.part
+        ))
+        .stack_size(options.stack_size)
+        .spawn(move || {
+            let (result, lines) =
+                log::capture(|| panic::catch_unwind(AssertUnwindSafe(|| solution(&text))));
+            let status = match result {
+                Ok(Ok(answer)) => Status::Ok(answer),
+                Ok(Err(e)) => Status::Error(format!("{:#}", util::name_input(e, &name))),
+                Err(payload) => Status::Panic(panic_message(payload)),
+            };
+            let _ = sender.send((status, start.elapsed(), lines));
+        });
+    if let Err(e) = spawned {
+        let status = Status::Error(format!("Unable to start a worker thread: {}", e));
+        return Outcome::new(puzzle, status, Duration::ZERO);
+    }
+
+    match receiver.recv_timeout(options.timeout) {
+        Ok((status, elapsed, lines)) => Outcome {
+            log: lines,
+            ..Outcome::new(puzzle, status, elapsed)
+        },
+        Err(_) => Outcome::new(puzzle, Status::Timeout, options.timeout),
+    }
+}
+
+pub struct Job {
+    pub puzzle: Puzzle,
+    pub input: Result<Input, String>,
+    pub cached: Option<Entry>,
+}
+
+impl Job {
+    pub fn to_json(&self, outcome: &Outcome) -> Value {
+        let (status, answer, error) = match &outcome.status {
+            Status::Ok(answer) => ("ok", Some(answer.clone()), None),
+            Status::Error(e) => ("error", None, Some(e.clone())),
+            Status::Panic(e) => ("panic", None, Some(e.clone())),
+            Status::Timeout => ("timeout", None, Some(outcome.status.to_string())),
+        };
+        let input = self.input.as_ref().ok();
+        json!({
+            "year": outcome.year,
+            "day": outcome.day,
+            "part": outcome.part,
+            "status": status,
+            "answer": answer,
+            "error": error,
+            "time_ns": outcome.elapsed.as_nanos() as u64,
+            "input": input.map(|x| &x.name),
+            "input_hash": input.map(|x| &x.hash),
+            "cached": outcome.cached,
+            "log": outcome.log,
+        })
+    }
+
+    fn run(&self, options: &Options) -> Outcome {
+        if let Some(entry) = &self.cached {
+            let status = Status::Ok(entry.answer.clone());
+            return Outcome {
+                cached: true,
+                ..Outcome::new(&self.puzzle, status, entry.elapsed)
+            };
+        }
+        match &self.input {
+            Ok(input) => run(&self.puzzle, input, options),
+            Err(e) => Outcome::new(&self.puzzle, Status::Error(e.clone()), Duration::ZERO),
+        }
+    }
+}
+
+// runs the jobs on `options.jobs` worker threads, `report` sees the outcomes in the order of the jobs
+pub fn run_all(
+    jobs: &[Job],
+    options: &Options,
+    mut report: impl FnMut(&Job, &Outcome),
+) -> Vec<Outcome> {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    let mut outcomes: Vec<Option<Outcome>> = vec![None; jobs.len()];
+
+    thread::scope(|scope| {
+        for _ in 0..options.jobs.clamp(1, jobs.len().max(1)) {
+            let sender = sender.clone();
+            let next = &next;
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::SeqCst);
+                if i >= jobs.len() {
+                    break;
+                }
+                let _ = sender.send((i, jobs[i].run(options)));
+            });
+        }
+        drop(sender);
+
+        let mut reported = 0;
+        for (i, outcome) in receiver {
+            outcomes[i] = Some(outcome);
+            while let Some(Some(outcome)) = outcomes.get(reported) {
+                report(&jobs[reported], outcome);
+                reported += 1;
+            }
+        }
+    });
+
+    outcomes.into_iter().map(|x| x.unwrap()).collect()
+}
+
+pub fn print_summary(outcomes: &[Outcome]) {
+    println!(
+        "{:>4} {:>3} {:>4}  {:<7} {:>10}  answer",
+        "year", "day", "part", "status", "time"
+    );
+    for o in outcomes {
+        let (status, detail) = match &o.status {
+            Status::Ok(answer) if o.cached => ("cached", answer.clone()),
+            Status::Ok(answer) => ("ok", answer.clone()),
+            Status::Error(e) => ("error", e.clone()),
+            Status::Panic(e) => ("panic", e.clone()),
+            Status::Timeout => ("timeout", String::new()),
+        };
+        // only the first line of multi-line answers and errors
+        let detail = detail.lines().next().unwrap_or_default();
+        println!(
+            "{:>4} {:>3} {:>4}  {:<7} {:>10.2?}  {}",
+            o.year, o.day, o.part, status, o.elapsed, detail
+        );
+    }
+    let failed = outcomes.iter().filter(|o| !o.is_ok()).count();
+    println!("{} ok, {} failed", outcomes.len() - failed, failed);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cache::{Entry, Key};
+    use crate::inputs::Input;
+    use crate::registry::{self, Puzzle};
+    use crate::runner::{run, run_all, Job, Options, Outcome, Status};
+    use anyhow::{bail, Result};
+    use std::time::Duration;
+
+    fn puzzle(solution: fn(&str) -> Result<String>) -> Puzzle {
+        Puzzle {
+            solution,
+            ..registry::find(2021, 1, 1).unwrap()
+        }
+    }
+
+    fn options() -> Options {
+        Options {
+            timeout: Duration::from_millis(200),
+            ..Options::default()
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let input = Input::new("x.txt", "1\n2\n").unwrap();
+        let ok = run(&puzzle(|s| Ok(s.replace('\n', ","))), &input, &options());
+        assert_eq!((1, 1), (ok.day, ok.part));
+        assert_eq!(Status::Ok("1,2".to_string()), ok.status);
+
+        let error = run(&puzzle(|_| bail!("no solution")), &input, &options());
+        assert_eq!(Status::Error("no solution".to_string()), error.status);
+
+        let logged = run(
+            &puzzle(|s| {
+                crate::util::log::write(
+                    "day1",
+                    crate::util::log::Level::Info,
+                    format_args!("{}", s.len()),
+                );
+                Ok(String::new())
+            }),
+            &input,
+            &options(),
+        );
+        assert_eq!(vec!["[INFO day1] 3"], logged.log);
+        assert!(ok.log.is_empty());
+
+        let panic = run(&puzzle(|_| panic!("sd")), &input, &options());
+        assert_eq!(Status::Panic("sd".to_string()), panic.status);
+
+        let timeout = run(
+            &puzzle(|_| {
+                std::thread::sleep(Duration::from_secs(5));
+                Ok(String::new())
+            }),
+            &input,
+            &options(),
+        );
+        assert_eq!(Status::Timeout, timeout.status);
+        assert!(!timeout.is_ok());
+    }
+
+    #[test]
+    fn test_run_all() {
+        let jobs = (1..=4)
+            .map(|day| Job {
+                puzzle: Puzzle {
+                    day,
+                    ..puzzle(|s| {
+                        // later jobs finish first
+                        let n: u64 = s.parse()?;
+                        std::thread::sleep(Duration::from_millis(100 - 20 * n));
+                        Ok(n.to_string())
+                    })
+                },
+                input: match day {
+                    3 => Err("missing".to_string()),
+                    _ => Ok(Input::new("x.txt", &day.to_string()).unwrap()),
+                },
+                cached: None,
+            })
+            .collect::<Vec<_>>();
+
+        for threads in [1, 4] {
+            let options = Options {
+                jobs: threads,
+                ..options()
+            };
+            let mut reported = vec![];
+            let outcomes = run_all(&jobs, &options, |_, o| reported.push(o.day));
+            assert_eq!(vec![1, 2, 3, 4], reported);
+            assert_eq!(Status::Ok("2".to_string()), outcomes[1].status);
+            assert_eq!(Status::Error("missing".to_string()), outcomes[2].status);
+        }
+    }
+
+    #[test]
+    fn test_cached() {
+        let job = Job {
+            puzzle: puzzle(|_| panic!("not cached")),
+            input: Ok(Input::new("x.txt", "1").unwrap()),
+            cached: Some(Entry {
+                key: Key {
+                    year: 2021,
+                    day: 1,
+                    part: 1,
+                    input_hash: String::new(),
+                    build: String::new(),
+                },
+                answer: "42".to_string(),
+                elapsed: Duration::from_secs(90),
+            }),
+        };
+        let outcomes = run_all(&[job], &options(), |_, _| {});
+        assert_eq!(Status::Ok("42".to_string()), outcomes[0].status);
+        assert_eq!(Duration::from_secs(90), outcomes[0].elapsed);
+        assert!(outcomes[0].cached);
+    }
+
+    #[test]
+    fn test_to_json() {
+        let job = Job {
+            puzzle: registry::find(2021, 7, 2).unwrap(),
+            input: Ok(Input::new("2021/day7.txt", "1,2").unwrap()),
+            cached: None,
+        };
+        let outcome = Outcome::new(
+            &job.puzzle,
+            Status::Ok("42".to_string()),
+            Duration::from_micros(5),
+        );
+        let v = job.to_json(&outcome);
+        assert_eq!(
+            (2021, 7, 2, "ok", "42", 5000),
+            (
+                v["year"].as_u64().unwrap(),
+                v["day"].as_u64().unwrap(),
+                v["part"].as_u64().unwrap(),
+                v["status"].as_str().unwrap(),
+                v["answer"].as_str().unwrap(),
+                v["time_ns"].as_u64().unwrap()
+            )
+        );
+        assert!(v["error"].is_null());
+        assert_eq!(0, v["log"].as_array().unwrap().len());
+        assert_eq!(64, v["input_hash"].as_str().unwrap().len());
+
+        let outcome = Outcome::new(&job.puzzle, Status::Panic("sd".to_string()), Duration::ZERO);
+        let v = job.to_json(&outcome);
+        assert_eq!("panic", v["status"]);
+        assert_eq!("sd", v["error"]);
+        assert!(v["answer"].is_null());
+    }
+}
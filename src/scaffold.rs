@@ -0,0 +1,347 @@
+use anyhow::{bail, Context, Result};
+use indoc::formatdoc;
+use itertools::Itertools;
+use regex::Regex;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = include_str!("template/mod.rs");
+
+pub fn render_template(year: u32, day: u32) -> String {
+    TEMPLATE
+        .replace(
+            "crate::template::",
+            &format!("crate::y{}::day{}::", year, day),
+        )
+        .replace("DayXX", &format!("Day{}", day))
+        .replace("\"dayXX.txt\"", &format!("\"{}/day{}.txt\"", year, day))
+}
+
+// the module of a new year with its first day
+pub fn render_year(year: u32, day: u32) -> String {
+    formatdoc!(
+        "
+        pub mod day{day};
+
+        use crate::registry::Puzzle;
+
+        pub const YEAR: u32 = {year};
+
+        pub fn puzzles() -> Vec<Puzzle> {{
+            crate::puzzles![
+                YEAR;
+                {day} => day{day}::Day{day},
+            ]
+        }}
+        ",
+        year = year,
+        day = day
+    )
+}
+
+// inserts `pub mod <name>;` into the sorted block of module declarations
+pub fn add_module(mod_rs: &str, name: &str) -> Result<String> {
+    let module = format!("pub mod {};", name);
+    let lines = mod_rs.lines().collect_vec();
+    if lines.contains(&module.as_str()) {
+        bail!("{} is already declared", module);
+    }
+
+    let mods = lines
+        .iter()
+        .positions(|l| l.starts_with("pub mod ") && l.ends_with(';'))
+        .collect_vec();
+    let declared = |i: usize| {
+        lines[i]
+            .trim_start_matches("pub mod ")
+            .trim_end_matches(';')
+    };
+    let i = match mods.iter().find(|&&i| declared(i) > name) {
+        Some(&i) => i,
+        None => match mods.last() {
+            Some(&i) => i + 1,
+            None => bail!("no module declarations found"),
+        },
+    };
+
+    Ok(join_lines(&lines[..i], &module, &lines[i..], mod_rs))
+}
+
+// adds the day to the `puzzles!` invocation of a year module
+pub fn add_puzzle(year_rs: &str, day: u32) -> Result<String> {
+    let entry = Regex::new(r"^(\s*)([0-9]+) => day[0-9]+::Day[0-9]+,$").unwrap();
+    let lines = year_rs.lines().collect_vec();
+    let entries = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| {
+            let c = entry.captures(l)?;
+            Some((i, c[1].to_string(), c[2].parse::<u32>().ok()?))
+        })
+        .collect_vec();
+    let indent = match entries.first() {
+        Some((_, indent, _)) => indent.clone(),
+        None => bail!("no puzzles! entries found"),
+    };
+    if entries.iter().any(|(_, _, d)| *d == day) {
+        bail!("day {} is already registered", day);
+    }
+    let i = match entries.iter().find(|(_, _, d)| *d > day) {
+        Some((i, _, _)) => *i,
+        None => entries.last().unwrap().0 + 1,
+    };
+    let line = format!("{}{} => day{}::Day{},", indent, day, day, day);
+
+    Ok(join_lines(&lines[..i], &line, &lines[i..], year_rs))
+}
+
+// adds the puzzles of a new year module to `registry::puzzles()`
+pub fn add_year(registry_rs: &str, year: u32) -> Result<String> {
+    let list = Regex::new(r"\[([\sa-z0-9_:(),]*)\]\.concat\(\)").unwrap();
+    let captures = match list.captures(registry_rs) {
+        Some(x) => x,
+        None => bail!("no `[..].concat()` list of year modules found"),
+    };
+
+    let year = format!("crate::y{}::puzzles()", year);
+    let mut years = captures[1]
+        .split(',')
+        .map(|x| x.trim())
+        .filter(|x| !x.is_empty())
+        .collect_vec();
+    if years.contains(&year.as_str()) {
+        bail!("{} is already registered", year);
+    }
+    years.push(&year);
+    years.sort_unstable();
+
+    let range = captures.get(0).unwrap().range();
+    Ok(format!(
+        "{}[{}].concat(){}",
+        &registry_rs[..range.start],
+        years.join(", "),
+        &registry_rs[range.end..]
+    ))
+}
+
+fn join_lines(before: &[&str], line: &str, after: &[&str], original: &str) -> String {
+    let mut r = before.iter().chain([line].iter()).chain(after).join("\n");
+    if original.ends_with('\n') {
+        r.push('\n');
+    }
+    r
+}
+
+// creates src/yYYYY/dayN/mod.rs from the template, registers it and creates empty inputs,
+// the module of the year is created as well if this is its first day
+pub fn new_day(root: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>> {
+    let src = root.join("src");
+    let year_dir = src.join(format!("y{}", year));
+    let module = year_dir.join(format!("day{}", day)).join("mod.rs");
+    if module.exists() {
+        bail!("{} already exists", module.display());
+    }
+
+    let year_rs = year_dir.join("mod.rs");
+    let mut changes = vec![];
+    if year_rs.exists() {
+        let s = add_module(&read(&year_rs)?, &format!("day{}", day))?;
+        changes.push((year_rs, add_puzzle(&s, day)?));
+    } else {
+        let lib_rs = src.join("lib.rs");
+        let registry_rs = src.join("registry.rs");
+        let lib = add_module(&read(&lib_rs)?, &format!("y{}", year))?;
+        let registry = add_year(&read(&registry_rs)?, year)?;
+        changes.push((year_rs, render_year(year, day)));
+        changes.push((lib_rs, lib));
+        changes.push((registry_rs, registry));
+    }
+
+    fs::create_dir_all(module.parent().unwrap())?;
+    write(&module, &render_template(year, day))?;
+    let mut written = vec![module];
+    for (path, s) in changes {
+        write(&path, &s)?;
+        written.push(path);
+    }
+
+    let inputs = root.join("inputs").join(year.to_string());
+    fs::create_dir_all(&inputs)?;
+    for name in [format!("day{}.txt", day), format!("day{}.example.txt", day)] {
+        let path = inputs.join(name);
+        if !path.exists() {
+            write(&path, "")?;
+            written.push(path);
+        }
+    }
+
+    Ok(written)
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("Unable to read {}", path.display()))
+}
+
+fn write(path: &Path, s: &str) -> Result<()> {
+    fs::write(path, s).with_context(|| format!("Unable to write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::inputs::tests::{temp_dir, Counting};
+    use crate::inputs::Inputs;
+    use crate::scaffold::{add_module, add_puzzle, add_year, new_day, render_template};
+    use indoc::indoc;
+    use std::cell::Cell;
+    use std::fs;
+    use std::rc::Rc;
+
+    const LIB: &str = indoc!(
+        "
+        pub mod answers;
+        pub mod util;
+        pub mod y2021;
+
+        pub trait Day {}
+        "
+    );
+
+    const YEAR: &str = indoc!(
+        "
+        pub mod day1;
+        pub mod day10;
+        pub mod day3;
+
+        pub fn puzzles() -> Vec<Puzzle> {
+            crate::puzzles![
+                YEAR;
+                1 => day1::Day1,
+                3 => day3::Day3,
+                10 => day10::Day10,
+            ]
+        }
+        "
+    );
+
+    const REGISTRY: &str = indoc!(
+        "
+        pub fn puzzles() -> Vec<Puzzle> {
+            [crate::y2021::puzzles()].concat()
+        }
+        "
+    );
+
+    #[test]
+    fn test_render_template() {
+        let s = render_template(2021, 7);
+        assert!(s.contains("pub struct Day7;"));
+        assert!(s.contains("impl Day for Day7 {"));
+        assert!(s.contains("use crate::run_solution;\n    use crate::y2021::day7::Day7;"));
+        assert!(s.contains("const INPUT: &str = \"2021/day7.txt\";"));
+        assert!(!s.contains("XX"));
+    }
+
+    #[test]
+    fn test_add_module() {
+        let s = add_module(YEAR, "day2").unwrap();
+        assert!(s.contains("pub mod day10;\npub mod day2;\npub mod day3;\n"));
+        let s = add_module(YEAR, "day11").unwrap();
+        assert!(s.contains("pub mod day10;\npub mod day11;\npub mod day3;\n"));
+        let s = add_module("pub mod day2;\npub mod day3;\n", "day1").unwrap();
+        assert_eq!("pub mod day1;\npub mod day2;\npub mod day3;\n", s);
+        assert!(add_module(YEAR, "day3").is_err());
+        let s = add_module(LIB, "y2022").unwrap();
+        assert!(s.contains("pub mod y2021;\npub mod y2022;\n"));
+    }
+
+    #[test]
+    fn test_add_puzzle() {
+        let s = add_puzzle(YEAR, 2).unwrap();
+        assert!(s.contains("1 => day1::Day1,\n        2 => day2::Day2,\n        3 => day3::Day3,"));
+        let s = add_puzzle(YEAR, 12).unwrap();
+        assert!(s.contains("10 => day10::Day10,\n        12 => day12::Day12,\n    ]"));
+        assert!(add_puzzle(YEAR, 10).is_err());
+
+        let year = include_str!("y2021/mod.rs")
+            .replace("pub mod day24;\n", "")
+            .replace("        24 => day24::Day24,\n", "");
+        let s = add_puzzle(&add_module(&year, "day24").unwrap(), 24).unwrap();
+        assert_eq!(include_str!("y2021/mod.rs"), s);
+    }
+
+    #[test]
+    fn test_add_year() {
+        let s = add_year(REGISTRY, 2022).unwrap();
+        assert!(s.contains("[crate::y2021::puzzles(), crate::y2022::puzzles()].concat()"));
+        let s = add_year(REGISTRY, 2020).unwrap();
+        assert!(s.contains("[crate::y2020::puzzles(), crate::y2021::puzzles()].concat()"));
+        assert!(add_year(REGISTRY, 2021).is_err());
+        assert!(add_year(include_str!("registry.rs"), 2022).is_ok());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src/y2021")).unwrap();
+        fs::write(root.join("src/lib.rs"), LIB).unwrap();
+        fs::write(root.join("src/registry.rs"), REGISTRY).unwrap();
+        fs::write(root.join("src/y2021/mod.rs"), YEAR).unwrap();
+
+        let written = new_day(&root, 2021, 4).unwrap();
+        assert_eq!(4, written.len());
+        let module = fs::read_to_string(root.join("src/y2021/day4/mod.rs")).unwrap();
+        assert!(module.contains("pub struct Day4;"));
+        let year = fs::read_to_string(root.join("src/y2021/mod.rs")).unwrap();
+        assert!(year.contains("pub mod day3;\npub mod day4;\n"));
+        assert!(year.contains("3 => day3::Day3,\n        4 => day4::Day4,\n"));
+        assert!(root.join("inputs/2021/day4.txt").exists());
+        assert!(root.join("inputs/2021/day4.example.txt").exists());
+        assert!(new_day(&root, 2021, 4).is_err());
+
+        // the first day of a year creates the module of the year
+        let written = new_day(&root, 2022, 1).unwrap();
+        assert_eq!(6, written.len());
+        let year = fs::read_to_string(root.join("src/y2022/mod.rs")).unwrap();
+        assert!(year.contains("pub mod day1;\n"));
+        assert!(year.contains("pub const YEAR: u32 = 2022;"));
+        assert!(add_puzzle(&year, 2).is_ok());
+        assert!(fs::read_to_string(root.join("src/lib.rs"))
+            .unwrap()
+            .contains("pub mod y2021;\npub mod y2022;\n"));
+        assert!(fs::read_to_string(root.join("src/registry.rs"))
+            .unwrap()
+            .contains("crate::y2022::puzzles()"));
+        assert!(root.join("inputs/2022/day1.txt").exists());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_new_day_downloads_input() {
+        let root = temp_dir("scaffold-download");
+        fs::create_dir_all(root.join("src/y2021")).unwrap();
+        fs::write(root.join("src/lib.rs"), LIB).unwrap();
+        fs::write(root.join("src/registry.rs"), REGISTRY).unwrap();
+        fs::write(root.join("src/y2021/mod.rs"), YEAR).unwrap();
+        new_day(&root, 2021, 4).unwrap();
+
+        // the empty input of a new day is downloaded on first use
+        let count = Rc::new(Cell::new(0));
+        let inputs = Inputs::new(root.join("inputs")).with_downloader(Counting(count.clone()));
+        assert_eq!("4\n2021", inputs.get(2021, 4).unwrap().trimmed());
+        assert_eq!(1, count.get());
+        let path = root.join("inputs/2021/day4.txt");
+        assert_eq!("4\n2021\n", fs::read_to_string(&path).unwrap());
+        assert_eq!("4\n2021", inputs.get(2021, 4).unwrap().trimmed());
+        assert_eq!(1, count.get());
+
+        // without a downloader it says why it can't be used
+        new_day(&root, 2021, 5).unwrap();
+        let e = Inputs::new(root.join("inputs")).get(2021, 5).unwrap_err();
+        assert!(e
+            .to_string()
+            .ends_with("is empty, set AOC_SESSION to download it"));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,393 @@
+use crate::inputs::USER_AGENT;
+use anyhow::{anyhow, bail, Context, Result};
+use itertools::Itertools;
+use regex::Regex;
+use serde_json::{json, Value};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const SUBMISSIONS_FILE: &str = "submissions.json";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    RateLimited,
+    AlreadySolved,
+}
+
+const VERDICTS: [(Verdict, &str); 6] = [
+    (Verdict::Correct, "correct"),
+    (Verdict::Incorrect, "incorrect"),
+    (Verdict::TooHigh, "too high"),
+    (Verdict::TooLow, "too low"),
+    (Verdict::RateLimited, "rate limited"),
+    (Verdict::AlreadySolved, "already solved"),
+];
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (_, name) = VERDICTS.iter().find(|(v, _)| v == self).unwrap();
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Verdict> {
+        match VERDICTS.iter().find(|(_, name)| *name == s) {
+            Some((v, _)) => Ok(*v),
+            None => bail!("unknown verdict {:?}", s),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Response {
+    pub verdict: Verdict,
+    pub wait: Option<Duration>,
+    pub message: String,
+}
+
+// the answer page is HTML, the message is the text of its <article>
+pub fn parse_response(html: &str) -> Result<Response> {
+    let article = Regex::new(r"(?s)<article>(.*?)</article>").unwrap();
+    let tag = Regex::new(r"<[^>]*>").unwrap();
+    let text = article
+        .captures(html)
+        .map_or(html, |c| c.get(1).unwrap().as_str());
+    let message = tag.replace_all(text, "").split_whitespace().join(" ");
+
+    let verdict = if message.contains("That's the right answer") {
+        Verdict::Correct
+    } else if message.contains("You gave an answer too recently") {
+        Verdict::RateLimited
+    } else if message.contains("You don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else if message.contains("That's not the right answer") {
+        if message.contains("too high") {
+            Verdict::TooHigh
+        } else if message.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Incorrect
+        }
+    } else {
+        bail!("unexpected response: {}", message);
+    };
+
+    let left = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    let minutes = Regex::new(r"(?i)wait (one|\d+) minutes? before trying again").unwrap();
+    let wait = if let Some(c) = left.captures(&message) {
+        let m = c.get(1).map_or(Ok(0), |x| x.as_str().parse::<u64>())?;
+        Some(Duration::from_secs(m * 60 + c[2].parse::<u64>()?))
+    } else if let Some(c) = minutes.captures(&message) {
+        let m = match &c[1] {
+            "one" => 1,
+            x => x.parse::<u64>()?,
+        };
+        Some(Duration::from_secs(m * 60))
+    } else {
+        None
+    };
+
+    Ok(Response {
+        verdict,
+        wait,
+        message,
+    })
+}
+
+pub struct Client {
+    pub url: String,
+    pub session: String,
+}
+
+impl Client {
+    pub fn submit(&self, year: u32, day: u32, part: u32, answer: &str) -> Result<Response> {
+        let url = format!(
+            "{}/{}/day/{}/answer",
+            self.url.trim_end_matches('/'),
+            year,
+            day
+        );
+        let response = ureq::post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+
+        match response {
+            Ok(r) => parse_response(&r.into_string()?),
+            Err(ureq::Error::Status(code, _)) => bail!("{} returned status {}", url, code),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Submission {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+// every evaluated submission and the earliest time (in unix seconds) of the next one
+#[derive(Debug, Default, PartialEq)]
+pub struct Log {
+    pub submissions: Vec<Submission>,
+    pub not_before: u64,
+}
+
+impl Log {
+    pub fn path() -> PathBuf {
+        PathBuf::from(SUBMISSIONS_FILE)
+    }
+
+    pub fn load(path: &Path) -> Result<Log> {
+        if !path.exists() {
+            return Ok(Default::default());
+        }
+        let s = fs::read_to_string(path)
+            .with_context(|| format!("Unable to read submissions {}", path.display()))?;
+        Self::from_json(&s).with_context(|| format!("Invalid submissions {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.to_json())
+            .with_context(|| format!("Unable to write submissions {}", path.display()))
+    }
+
+    pub fn to_json(&self) -> String {
+        let submissions = self
+            .submissions
+            .iter()
+            .map(|s| {
+                json!({
+                    "year": s.year,
+                    "day": s.day,
+                    "part": s.part,
+                    "answer": s.answer,
+                    "verdict": s.verdict.to_string(),
+                })
+            })
+            .collect_vec();
+        let v = json!({ "not_before": self.not_before, "submissions": submissions });
+        serde_json::to_string_pretty(&v).unwrap()
+    }
+
+    pub fn from_json(s: &str) -> Result<Log> {
+        let v: Value = serde_json::from_str(s)?;
+        let submissions = v["submissions"]
+            .as_array()
+            .ok_or_else(|| anyhow!("Missing submissions array"))?
+            .iter()
+            .map(|s| {
+                Ok(Submission {
+                    // logs written before there was more than one year are all of 2021
+                    year: s["year"].as_u64().unwrap_or(2021) as u32,
+                    day: s["day"].as_u64().ok_or_else(|| anyhow!("Invalid day"))? as u32,
+                    part: s["part"].as_u64().ok_or_else(|| anyhow!("Invalid part"))? as u32,
+                    answer: s["answer"].as_str().unwrap_or_default().to_string(),
+                    verdict: s["verdict"].as_str().unwrap_or_default().parse()?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Log {
+            submissions,
+            not_before: v["not_before"].as_u64().unwrap_or(0),
+        })
+    }
+
+    // refuses answers that are known to be wrong and submissions before the wait time is over
+    pub fn check(&self, year: u32, day: u32, part: u32, answer: &str, now: u64) -> Result<()> {
+        let previous = self
+            .submissions
+            .iter()
+            .filter(|s| s.year == year && s.day == day && s.part == part)
+            .collect_vec();
+
+        if let Some(s) = previous.iter().find(|s| s.verdict == Verdict::Correct) {
+            bail!(
+                "{} day {} part {} was already solved with {:?}",
+                year,
+                day,
+                part,
+                s.answer
+            );
+        }
+        if let Some(s) = previous.iter().find(|s| s.answer == answer) {
+            bail!("{:?} was already submitted and is {}", answer, s.verdict);
+        }
+        if let Ok(x) = answer.parse::<i64>() {
+            for s in previous.iter() {
+                match (s.verdict, s.answer.parse::<i64>()) {
+                    (Verdict::TooHigh, Ok(y)) if x >= y => {
+                        bail!("{} cannot be right, {} is already too high", x, y)
+                    }
+                    (Verdict::TooLow, Ok(y)) if x <= y => {
+                        bail!("{} cannot be right, {} is already too low", x, y)
+                    }
+                    _ => {}
+                }
+            }
+        }
+        if now < self.not_before {
+            bail!(
+                "Submissions are throttled, try again in {}s",
+                self.not_before - now
+            );
+        }
+        Ok(())
+    }
+
+    pub fn record(
+        &mut self,
+        year: u32,
+        day: u32,
+        part: u32,
+        answer: &str,
+        response: &Response,
+        now: u64,
+    ) {
+        if response.verdict != Verdict::RateLimited {
+            self.submissions.push(Submission {
+                year,
+                day,
+                part,
+                answer: answer.to_string(),
+                verdict: response.verdict,
+            });
+        }
+        if let Some(wait) = response.wait {
+            self.not_before = now + wait.as_secs();
+        }
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+pub fn submit(
+    client: &Client,
+    log_path: &Path,
+    year: u32,
+    day: u32,
+    part: u32,
+    answer: &str,
+) -> Result<Response> {
+    let mut log = Log::load(log_path)?;
+    log.check(year, day, part, answer, now())?;
+    let response = client.submit(year, day, part, answer)?;
+    log.record(year, day, part, answer, &response, now());
+    log.save(log_path)?;
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::inputs::tests::{mock_server, temp_dir};
+    use crate::submit::{parse_response, submit, Client, Log, Response, Verdict};
+    use std::fs;
+    use std::time::Duration;
+
+    const CORRECT: &str = "<main>\n<article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to saving your vacation. <a href=\"/2021/day/7#part2\">[Continue to Part Two]</a></p></article>\n</main>";
+    const TOO_HIGH: &str = "<main>\n<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2021/about\">about page</a>.  Please wait one minute before trying again. (You guessed <span style=\"white-space:nowrap;\"><code>100</code>.)</span> <a href=\"/2021/day/7\">[Return to Day 7]</a></p></article>\n</main>";
+    const TOO_RECENT: &str = "<main>\n<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 32s left to wait. <a href=\"/2021/day/7\">[Return to Day 7]</a></p></article>\n</main>";
+    const WRONG_LEVEL: &str = "<article><p>You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2021/day/7\">[Return to Day 7]</a></p></article>";
+
+    #[test]
+    fn test_parse_response() {
+        let r = parse_response(CORRECT).unwrap();
+        assert_eq!((Verdict::Correct, None), (r.verdict, r.wait));
+        assert!(r
+            .message
+            .starts_with("That's the right answer! You are one gold star closer"));
+
+        let r = parse_response(TOO_HIGH).unwrap();
+        let wait = Some(Duration::from_secs(60));
+        assert_eq!((Verdict::TooHigh, wait), (r.verdict, r.wait));
+
+        let r = parse_response(TOO_RECENT).unwrap();
+        let wait = Some(Duration::from_secs(92));
+        assert_eq!((Verdict::RateLimited, wait), (r.verdict, r.wait));
+
+        let r = parse_response(WRONG_LEVEL).unwrap();
+        assert_eq!(Verdict::AlreadySolved, r.verdict);
+        assert!(parse_response("<html>Maintenance</html>").is_err());
+    }
+
+    #[test]
+    fn test_log() {
+        let mut log = Log::default();
+        let too_high = parse_response(TOO_HIGH).unwrap();
+        log.record(2021, 7, 1, "100", &too_high, 1000);
+        assert_eq!(1060, log.not_before);
+
+        assert!(log.check(2021, 7, 1, "50", 1059).is_err());
+        assert!(log.check(2021, 7, 1, "50", 1060).is_ok());
+        assert!(log.check(2021, 7, 1, "100", 2000).is_err());
+        assert!(log.check(2021, 7, 1, "101", 2000).is_err());
+        assert!(log.check(2021, 7, 2, "101", 2000).is_ok());
+        assert!(log.check(2020, 7, 1, "101", 2000).is_ok());
+
+        let rate_limited = parse_response(TOO_RECENT).unwrap();
+        log.record(2021, 7, 1, "50", &rate_limited, 2000);
+        assert_eq!(1, log.submissions.len());
+        assert_eq!(2092, log.not_before);
+
+        let correct = Response {
+            verdict: Verdict::Correct,
+            wait: None,
+            message: String::new(),
+        };
+        log.record(2021, 7, 1, "50", &correct, 3000);
+        assert!(log.check(2021, 7, 1, "49", 3000).is_err());
+        assert_eq!(log, Log::from_json(&log.to_json()).unwrap());
+
+        let old = r#"{"not_before": 0, "submissions": [{"day": 7, "part": 1, "answer": "1", "verdict": "too low"}]}"#;
+        assert_eq!(2021, Log::from_json(old).unwrap().submissions[0].year);
+    }
+
+    #[test]
+    fn test_submit() {
+        let dir = temp_dir("submit");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("submissions.json");
+
+        let (url, server) = mock_server("200 OK", TOO_HIGH);
+        let client = Client {
+            url,
+            session: "secret".to_string(),
+        };
+        let r = submit(&client, &path, 2021, 7, 2, "100").unwrap();
+        assert_eq!(Verdict::TooHigh, r.verdict);
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2021/day/7/answer HTTP/1.1\r\n"));
+        assert!(request.contains("\r\nCookie: session=secret\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=100"));
+
+        let log = Log::load(&path).unwrap();
+        assert_eq!(1, log.submissions.len());
+        let e = submit(&client, &path, 2021, 7, 2, "100").unwrap_err();
+        assert_eq!(
+            "\"100\" was already submitted and is too high",
+            e.to_string()
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
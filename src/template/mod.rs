@@ -1,71 +1,57 @@
-use crate::util::{parse_lines, parse_lines_regex};
+use crate::Day;
 use anyhow::Result;
-use itertools::{izip, Itertools};
-use std::collections::{HashMap, HashSet};
+use itertools::Itertools;
 
-fn solution_regex(input: &str) -> Result<String> {
-    let xs = parse_lines_regex(input, "^(.+?) (.+?) (.+?) (.+?)$")?;
+pub struct DayXX;
 
-    let ys = xs
-        .iter()
-        .map(|l| {
-            let (v1, v2, v3, v4) = (
-                l[1].as_str(),
-                l[2].as_str(),
-                l[3].as_str(),
-                l[4].parse::<i32>().unwrap(),
-            );
+impl Day for DayXX {
+    type Input = Vec<String>;
 
-            dbg!(v1.as_ref())
-        })
-        .collect::<Vec<&str>>();
-
-    Ok(format!("{}", "??"))
-}
-
-fn solution_numbers(input: &str) -> Result<String> {
-    let xs = parse_lines::<i32>(input)?;
-
-    let ys = xs.iter().map(|x| dbg!(*x)).collect::<Vec<i32>>();
-
-    Ok(format!("{}", "??"))
-}
+    fn parse(input: &str) -> Result<Vec<String>> {
+        Ok(input.lines().map(|x| x.to_string()).collect_vec())
+    }
 
-fn solution1(input: &str) -> Result<String> {
-    Ok(format!("{}", "?"))
-}
+    fn part1(_xs: &Vec<String>) -> Result<String> {
+        Ok("?".to_string())
+    }
 
-fn solution2(input: &str) -> Result<String> {
-    Ok(format!("{}", "?"))
+    fn part2(_xs: &Vec<String>) -> Result<String> {
+        Ok("?".to_string())
+    }
 }
 
+#[cfg(test)]
 mod tests {
     use crate::run_solution;
-    use crate::template::{solution1, solution2};
+    use crate::template::DayXX;
+    use crate::Day;
     use indoc::indoc;
-    const INPUT: &'static str = "dayXX.txt";
+    const INPUT: &str = "dayXX.txt";
 
     // PART 1
 
+    // the answers of the example, "?" until the part is solved
     #[test]
     fn test_part1() {
-        assert_eq!("??", solution1(indoc!("")).unwrap());
+        assert_eq!("?", DayXX::solution1(indoc!("")).unwrap());
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn run_solution1() {
-        run_solution(INPUT, solution1).unwrap()
+        run_solution(INPUT, DayXX::solution1).unwrap()
     }
 
     // PART 2
 
     #[test]
     fn test_part2() {
-        assert_eq!("??", solution2(indoc!("")).unwrap());
+        assert_eq!("?", DayXX::solution2(indoc!("")).unwrap());
     }
 
     #[test]
+    #[ignore = "needs the puzzle input"]
     fn run_solution2() {
-        run_solution(INPUT, solution2).unwrap()
+        run_solution(INPUT, DayXX::solution2).unwrap()
     }
 }
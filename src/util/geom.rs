@@ -0,0 +1,470 @@
+use crate::util::ParseError;
+use itertools::Itertools;
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+// the signed integers coordinates are made of
+pub trait Coord:
+    Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+    fn as_i64(self) -> i64;
+}
+
+macro_rules! coord {
+    ($($t:ty),+) => {$(
+        impl Coord for $t {
+            const ZERO: $t = 0;
+            const ONE: $t = 1;
+
+            fn abs(self) -> $t {
+                <$t>::abs(self)
+            }
+
+            fn signum(self) -> $t {
+                <$t>::signum(self)
+            }
+
+            fn as_i64(self) -> i64 {
+                self as i64
+            }
+        }
+    )+};
+}
+
+coord!(i8, i16, i32, i64, isize);
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec2<T>(pub T, pub T);
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec3<T>(pub T, pub T, pub T);
+
+// component-wise arithmetic, scaling and the distance metrics shared by both vectors
+macro_rules! vector {
+    ($v:ident, $($i:tt),+) => {
+        impl<T: Coord> $v<T> {
+            pub fn map(self, f: impl Fn(T) -> T) -> $v<T> {
+                $v($(f(self.$i)),+)
+            }
+
+            pub fn zip_with(self, o: $v<T>, f: impl Fn(T, T) -> T) -> $v<T> {
+                $v($(f(self.$i, o.$i)),+)
+            }
+
+            pub fn min(self, o: $v<T>) -> $v<T> {
+                self.zip_with(o, Ord::min)
+            }
+
+            pub fn max(self, o: $v<T>) -> $v<T> {
+                self.zip_with(o, Ord::max)
+            }
+
+            pub fn abs(self) -> $v<T> {
+                self.map(T::abs)
+            }
+
+            pub fn signum(self) -> $v<T> {
+                self.map(T::signum)
+            }
+
+            // the sum of the absolute components
+            pub fn norm1(self) -> T {
+                T::ZERO $(+ self.$i.abs())+
+            }
+
+            // the largest absolute component
+            pub fn norm_max(self) -> T {
+                T::ZERO $(.max(self.$i.abs()))+
+            }
+
+            pub fn manhattan(self, o: $v<T>) -> T {
+                (self - o).norm1()
+            }
+
+            pub fn chebyshev(self, o: $v<T>) -> T {
+                (self - o).norm_max()
+            }
+
+            // true if every component is below the one of `o`
+            pub fn all_lt(self, o: $v<T>) -> bool {
+                true $(&& self.$i < o.$i)+
+            }
+
+            pub fn all_le(self, o: $v<T>) -> bool {
+                true $(&& self.$i <= o.$i)+
+            }
+        }
+
+        impl<T: Coord> Add for $v<T> {
+            type Output = $v<T>;
+
+            fn add(self, rhs: $v<T>) -> $v<T> {
+                self.zip_with(rhs, Add::add)
+            }
+        }
+
+        impl<T: Coord> Sub for $v<T> {
+            type Output = $v<T>;
+
+            fn sub(self, rhs: $v<T>) -> $v<T> {
+                self.zip_with(rhs, Sub::sub)
+            }
+        }
+
+        impl<T: Coord> Neg for $v<T> {
+            type Output = $v<T>;
+
+            fn neg(self) -> $v<T> {
+                self.map(Neg::neg)
+            }
+        }
+
+        impl<T: Coord> Mul<T> for $v<T> {
+            type Output = $v<T>;
+
+            fn mul(self, rhs: T) -> $v<T> {
+                self.map(|x| x * rhs)
+            }
+        }
+
+        impl<T: Coord> AddAssign for $v<T> {
+            fn add_assign(&mut self, rhs: $v<T>) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<T: Coord> SubAssign for $v<T> {
+            fn sub_assign(&mut self, rhs: $v<T>) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl<T: Display> Display for $v<T> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                write!(f, "[{}]", [$(self.$i.to_string()),+].join(","))
+            }
+        }
+    };
+}
+
+vector!(Vec2, 0, 1);
+vector!(Vec3, 0, 1, 2);
+
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Vec2<T> {
+        Vec2(x, y)
+    }
+}
+
+impl<T> From<(T, T, T)> for Vec3<T> {
+    fn from((x, y, z): (T, T, T)) -> Vec3<T> {
+        Vec3(x, y, z)
+    }
+}
+
+// a 3x3 matrix of -1, 0 and 1 entries, i.e. a rotation or reflection of the axes
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Mat3(pub [[i32; 3]; 3]);
+
+impl Mat3 {
+    pub const IDENTITY: Mat3 = Mat3([[1, 0, 0], [0, 1, 0], [0, 0, 1]]);
+
+    pub fn transpose(&self) -> Mat3 {
+        let m = &self.0;
+        Mat3([0, 1, 2].map(|r| [0, 1, 2].map(|c| m[c][r])))
+    }
+
+    pub fn det(&self) -> i32 {
+        let m = &self.0;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+}
+
+impl Mul for Mat3 {
+    type Output = Mat3;
+
+    fn mul(self, rhs: Mat3) -> Mat3 {
+        let (a, b) = (&self.0, &rhs.0);
+        Mat3([0, 1, 2].map(|r| [0, 1, 2].map(|c| (0..3).map(|i| a[r][i] * b[i][c]).sum())))
+    }
+}
+
+impl<T: Coord> Mul<Vec3<T>> for Mat3 {
+    type Output = Vec3<T>;
+
+    fn mul(self, v: Vec3<T>) -> Vec3<T> {
+        let component = |row: [i32; 3]| {
+            [v.0, v.1, v.2]
+                .into_iter()
+                .zip(row)
+                .fold(T::ZERO, |acc, (x, m)| match m {
+                    0 => acc,
+                    1 => acc + x,
+                    -1 => acc - x,
+                    _ => panic!("not a rotation matrix: {:?}", self),
+                })
+        };
+        Vec3(
+            component(self.0[0]),
+            component(self.0[1]),
+            component(self.0[2]),
+        )
+    }
+}
+
+// the 24 orientations of a cube: every permutation of the axes with every choice of signs that
+// does not mirror, the identity comes first
+pub fn rotations() -> Vec<Mat3> {
+    (0..3)
+        .permutations(3)
+        .cartesian_product(0..8)
+        .map(|(axes, signs)| {
+            let mut m = [[0; 3]; 3];
+            for (r, &c) in axes.iter().enumerate() {
+                m[r][c] = if signs & (1 << r) == 0 { 1 } else { -1 };
+            }
+            Mat3(m)
+        })
+        .filter(|m| m.det() == 1)
+        .collect()
+}
+
+// an axis-aligned rectangle, `min` is inside and `max` is not
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Rect<T> {
+    pub min: Vec2<T>,
+    pub max: Vec2<T>,
+}
+
+// an axis-aligned cuboid, `min` is inside and `max` is not
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Cuboid<T> {
+    pub min: Vec3<T>,
+    pub max: Vec3<T>,
+}
+
+macro_rules! aabb {
+    ($b:ident, $v:ident, $($i:tt),+) => {
+        impl<T: Coord> $b<T> {
+            pub fn half_open(min: $v<T>, max: $v<T>) -> $b<T> {
+                $b { min, max }
+            }
+
+            // the box spanned by two corners that are both inside, in any order
+            pub fn inclusive(a: $v<T>, b: $v<T>) -> $b<T> {
+                $b {
+                    min: a.min(b),
+                    max: a.max(b).map(|x| x + T::ONE),
+                }
+            }
+
+            // the largest corner that is still inside
+            pub fn last(&self) -> $v<T> {
+                self.max.map(|x| x - T::ONE)
+            }
+
+            pub fn is_empty(&self) -> bool {
+                !self.min.all_lt(self.max)
+            }
+
+            pub fn contains(&self, p: $v<T>) -> bool {
+                self.min.all_le(p) && p.all_lt(self.max)
+            }
+
+            pub fn intersection(&self, o: &$b<T>) -> Option<$b<T>> {
+                Some($b::half_open(self.min.max(o.min), self.max.min(o.max)))
+                    .filter(|x| !x.is_empty())
+            }
+
+            // the number of integer points inside
+            pub fn volume(&self) -> u64 {
+                let size = self.max - self.min;
+                1 $(* size.$i.as_i64().max(0) as u64)+
+            }
+        }
+    };
+}
+
+aabb!(Rect, Vec2, 0, 1);
+aabb!(Cuboid, Vec3, 0, 1, 2);
+
+impl Rect<i32> {
+    // the points inside ordered by x, then y
+    pub fn points(&self) -> impl Iterator<Item = Vec2<i32>> {
+        let (min, max) = (self.min, self.max);
+        (min.0..max.0).flat_map(move |x| (min.1..max.1).map(move |y| Vec2(x, y)))
+    }
+}
+
+impl Cuboid<i32> {
+    // the points inside ordered by x, then y, then z
+    pub fn points(&self) -> impl Iterator<Item = Vec3<i32>> {
+        let (min, max) = (self.min, self.max);
+        (min.0..max.0).flat_map(move |x| {
+            (min.1..max.1).flat_map(move |y| (min.2..max.2).map(move |z| Vec3(x, y, z)))
+        })
+    }
+}
+
+// parses the inclusive ranges of "x=a..b, y=c..d" in the order of `axes`
+fn parse_ranges<T: Coord + FromStr>(s: &str, axes: &[&str]) -> Result<Vec<(T, T)>, ParseError> {
+    let mut r = vec![];
+    let mut start = 0;
+    let fields = s.split(',').collect_vec();
+    if fields.len() != axes.len() {
+        let expected = format!("{} ranges", axes.len());
+        return Err(ParseError::whole_line(1, s, &expected));
+    }
+    for (field, axis) in fields.into_iter().zip(axes) {
+        let columns = start..start + field.len();
+        start = columns.end + 1;
+        let range = field
+            .trim()
+            .strip_prefix(axis)
+            .and_then(|x| x.strip_prefix('='))
+            .and_then(|x| x.split_once(".."))
+            .and_then(|(a, b)| Some((a.parse::<T>().ok()?, b.parse::<T>().ok()?)));
+        match range {
+            Some(x) => r.push(x),
+            None => {
+                let expected = format!("a range like `{}=a..b`", axis);
+                return Err(ParseError::new(1, s, columns, &expected));
+            }
+        }
+    }
+    Ok(r)
+}
+
+// "x=20..30, y=-10..-5", both ends are inside
+impl<T: Coord + FromStr> FromStr for Rect<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Rect<T>, ParseError> {
+        let r = parse_ranges::<T>(s, &["x", "y"])?;
+        Ok(Rect::inclusive(Vec2(r[0].0, r[1].0), Vec2(r[0].1, r[1].1)))
+    }
+}
+
+// "x=-20..26,y=-36..17,z=-47..7", both ends are inside
+impl<T: Coord + FromStr> FromStr for Cuboid<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Cuboid<T>, ParseError> {
+        let r = parse_ranges::<T>(s, &["x", "y", "z"])?;
+        Ok(Cuboid::inclusive(
+            Vec3(r[0].0, r[1].0, r[2].0),
+            Vec3(r[0].1, r[1].1, r[2].1),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::geom::{rotations, Cuboid, Mat3, Rect, Vec2, Vec3};
+    use itertools::Itertools;
+
+    #[test]
+    fn test_vectors() {
+        let a = Vec3(1, -2, 3);
+        let b = Vec3(-4, 5, 6);
+        assert_eq!(Vec3(-3, 3, 9), a + b);
+        assert_eq!(Vec3(5, -7, -3), a - b);
+        assert_eq!(Vec3(-2, 4, -6), -a * 2);
+        assert_eq!(Vec3(1, -1, 1), a.signum());
+        assert_eq!(Vec3(-4, -2, 3), a.min(b));
+        assert_eq!(15, a.manhattan(b));
+        assert_eq!(7, a.chebyshev(b));
+        assert_eq!("[1,-2,3]", a.to_string());
+
+        let mut p = Vec2(3i64, 4);
+        p += Vec2(1, 1);
+        p -= Vec2(10, 0);
+        assert_eq!(Vec2(-6, 5), p);
+        assert_eq!(11, p.norm1());
+        assert_eq!(6, p.norm_max());
+        assert_eq!(Vec2(1, 2), (1, 2).into());
+    }
+
+    #[test]
+    fn test_rotations() {
+        let rs = rotations();
+        assert_eq!(24, rs.len());
+        assert_eq!(Mat3::IDENTITY, rs[0]);
+        assert_eq!(24, rs.iter().unique().count());
+
+        // closed under composition and the transpose undoes a rotation
+        for &a in &rs {
+            assert_eq!(Mat3::IDENTITY, a * a.transpose());
+            for &b in &rs {
+                assert!(rs.contains(&(a * b)));
+            }
+        }
+
+        // every orientation of a point with distinct components is reached exactly once
+        let p = Vec3(1, 2, 3);
+        assert_eq!(24, rs.iter().map(|&r| r * p).unique().count());
+        let quarter = Mat3([[0, -1, 0], [1, 0, 0], [0, 0, 1]]);
+        assert!(rs.contains(&quarter));
+        assert_eq!(Vec3(-2, 1, 3), quarter * p);
+        assert_eq!(p, quarter.transpose() * (quarter * p));
+    }
+
+    #[test]
+    fn test_boxes() {
+        let a = Cuboid::inclusive(Vec3(10, 12, 10), Vec3(12, 10, 12));
+        assert_eq!(Vec3(13, 13, 13), a.max);
+        assert_eq!(Vec3(12, 12, 12), a.last());
+        assert_eq!(27, a.volume());
+        assert_eq!(27, a.points().count());
+        assert!(a.contains(Vec3(12, 12, 12)));
+        assert!(!a.contains(Vec3(13, 12, 12)));
+
+        let b = Cuboid::half_open(Vec3(11, 11, 11), Vec3(14, 14, 14));
+        assert_eq!(
+            Some(Cuboid::half_open(Vec3(11, 11, 11), Vec3(13, 13, 13))),
+            a.intersection(&b)
+        );
+        let c = Cuboid::half_open(Vec3(13, 0, 0), Vec3(20, 20, 20));
+        assert_eq!(None, a.intersection(&c));
+        assert!(Cuboid::half_open(Vec3(1, 1, 1), Vec3(1, 5, 5)).is_empty());
+
+        let r = Rect::inclusive(Vec2(20, -10), Vec2(30, -5));
+        assert_eq!(66, r.volume());
+        assert!(r.contains(Vec2(28, -7)));
+        assert!(!r.contains(Vec2(28, -4)));
+        assert_eq!(
+            vec![Vec2(0, 0), Vec2(0, 1), Vec2(1, 0), Vec2(1, 1)],
+            Rect::half_open(Vec2(0, 0), Vec2(2, 2))
+                .points()
+                .collect_vec()
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Rect::inclusive(Vec2(20, -10), Vec2(30, -5)),
+            "x=20..30, y=-10..-5".parse::<Rect<i32>>().unwrap()
+        );
+        assert_eq!(
+            Cuboid::inclusive(Vec3(-20, -36, -47), Vec3(26, 17, 7)),
+            "x=-20..26,y=-36..17,z=-47..7"
+                .parse::<Cuboid<i64>>()
+                .unwrap()
+        );
+
+        let e = "x=1..2,y=3,z=4..5".parse::<Cuboid<i32>>().unwrap_err();
+        assert_eq!(7..10, e.columns);
+        assert_eq!("a range like `y=a..b`", e.expected);
+        assert!("x=1..2".parse::<Cuboid<i32>>().is_err());
+        assert!("y=1..2, x=3..4".parse::<Rect<i32>>().is_err());
+    }
+}
@@ -0,0 +1,294 @@
+use crate::util::ParseError;
+use itertools::Itertools;
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut, Range};
+
+// (x, y), with y growing downwards
+pub type Pos = (usize, usize);
+
+pub const ORTHOGONAL: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+pub const DIAGONAL: [(i64, i64); 4] = [(-1, -1), (1, -1), (1, 1), (-1, 1)];
+pub const ADJACENT: [(i64, i64); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(width * height, cells.len());
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Pos) -> T) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+        Self::new(width, height, cells)
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let width = rows.first().map_or(0, |x| x.len());
+        let height = rows.len();
+        assert!(
+            rows.iter().all(|x| x.len() == width),
+            "rows differ in width"
+        );
+        Self::new(width, height, rows.into_iter().flatten().collect())
+    }
+
+    // parses a character map, `f` returns None for characters that are not allowed
+    pub fn parse(
+        s: &str,
+        expected: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut rows: Vec<Vec<T>> = vec![];
+        for (i, l) in s.lines().enumerate() {
+            let row = l
+                .char_indices()
+                .map(|(j, c)| {
+                    f(c).ok_or_else(|| ParseError::new(i + 1, l, j..j + c.len_utf8(), expected))
+                })
+                .collect::<Result<Vec<T>, ParseError>>()?;
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    let expected = format!("{} cells", first.len());
+                    return Err(ParseError::whole_line(i + 1, l, &expected));
+                }
+            }
+            rows.push(row);
+        }
+        Ok(Self::from_rows(rows))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        match self.contains(pos) {
+            true => Some(&self.cells[pos.1 * self.width + pos.0]),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        match self.contains(pos) {
+            true => Some(&mut self.cells[pos.1 * self.width + pos.0]),
+            false => None,
+        }
+    }
+
+    // moves `pos` by `delta`, None if it leaves the grid
+    pub fn offset(&self, (x, y): Pos, (dx, dy): (i64, i64)) -> Option<Pos> {
+        let x = usize::try_from(x as i64 + dx).ok()?;
+        let y = usize::try_from(y as i64 + dy).ok()?;
+        Some((x, y)).filter(|&p| self.contains(p))
+    }
+
+    // moves `pos` by `delta`, wrapping around the edges
+    pub fn offset_wrapping(&self, (x, y): Pos, (dx, dy): (i64, i64)) -> Pos {
+        (
+            (x as i64 + dx).rem_euclid(self.width as i64) as usize,
+            (y as i64 + dy).rem_euclid(self.height as i64) as usize,
+        )
+    }
+
+    pub fn get_wrapping(&self, pos: Pos, delta: (i64, i64)) -> &T {
+        &self[self.offset_wrapping(pos, delta)]
+    }
+
+    pub fn neighbours<'a>(
+        &self,
+        pos: Pos,
+        deltas: &'a [(i64, i64)],
+    ) -> impl Iterator<Item = Pos> + 'a {
+        let (width, height) = (self.width, self.height);
+        deltas.iter().filter_map(move |&(dx, dy)| {
+            let x = usize::try_from(pos.0 as i64 + dx).ok()?;
+            let y = usize::try_from(pos.1 as i64 + dy).ok()?;
+            Some((x, y)).filter(|&(x, y)| x < width && y < height)
+        })
+    }
+
+    pub fn orthogonal(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        self.neighbours(pos, &ORTHOGONAL)
+    }
+
+    pub fn diagonal(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        self.neighbours(pos, &DIAGONAL)
+    }
+
+    pub fn adjacent(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        self.neighbours(pos, &ADJACENT)
+    }
+
+    // all positions in row-major order
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    // the cells of a rectangle, clipped to the grid
+    pub fn region(&self, xs: Range<usize>, ys: Range<usize>) -> impl Iterator<Item = (Pos, &T)> {
+        let xs = xs.start.min(self.width)..xs.end.min(self.width);
+        let ys = ys.start.min(self.height)..ys.end.min(self.height);
+        ys.flat_map(move |y| xs.clone().map(move |x| (x, y)))
+            .map(move |p| (p, &self[p]))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): Pos) -> &T {
+        assert!(self.contains((x, y)), "{:?} is outside of the grid", (x, y));
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (x, y): Pos) -> &mut T {
+        assert!(self.contains((x, y)), "{:?} is outside of the grid", (x, y));
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().join(""))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::grid::Grid;
+    use itertools::Itertools;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef", "a letter", Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let g = grid();
+        assert_eq!((3, 2), (g.width(), g.height()));
+        assert_eq!('f', g[(2, 1)]);
+        assert_eq!("abc\ndef\n", g.to_string());
+
+        let e = Grid::parse("12\n3x", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((2, 1..2), (e.line, e.columns));
+        let e = Grid::parse("12\n345", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((2, "2 cells"), (e.line, e.expected.as_str()));
+    }
+
+    #[test]
+    fn test_indexing() {
+        let mut g = grid();
+        assert_eq!(None, g.get((3, 0)));
+        assert_eq!(Some(&'d'), g.get((0, 1)));
+        assert_eq!(None, g.offset((0, 0), (-1, 0)));
+        assert_eq!(Some((1, 1)), g.offset((0, 0), (1, 1)));
+        assert_eq!((2, 1), g.offset_wrapping((0, 0), (-1, -1)));
+        assert_eq!(&'a', g.get_wrapping((2, 1), (1, 1)));
+
+        g[(1, 0)] = 'x';
+        *g.get_mut((2, 0)).unwrap() = 'y';
+        assert_eq!("axy\ndef\n", g.to_string());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let g = grid();
+        assert_eq!(vec![(1, 0), (0, 1)], g.orthogonal((0, 0)).collect_vec());
+        assert_eq!(vec![(2, 1), (0, 1)], g.diagonal((1, 0)).collect_vec());
+        assert_eq!(5, g.adjacent((1, 1)).count());
+        assert_eq!(3, g.adjacent((2, 1)).count());
+    }
+
+    #[test]
+    fn test_iteration() {
+        let g = grid();
+        assert_eq!(
+            vec![&['a', 'b', 'c'][..], &['d', 'e', 'f']],
+            g.rows().collect_vec()
+        );
+        assert_eq!(vec![&'b', &'e'], g.column(1).collect_vec());
+        assert_eq!(
+            vec![
+                ((1, 0), &'b'),
+                ((2, 0), &'c'),
+                ((1, 1), &'e'),
+                ((2, 1), &'f')
+            ],
+            g.region(1..5, 0..2).collect_vec()
+        );
+        assert_eq!((2, 1), g.iter().last().unwrap().0);
+        assert_eq!("ABC\nDEF\n", g.map(|c| c.to_ascii_uppercase()).to_string());
+        assert_eq!(
+            Grid::from_fn(2, 2, |(x, y)| x + 2 * y),
+            Grid::from_rows(vec![vec![0, 1], vec![2, 3]])
+        );
+    }
+}
@@ -0,0 +1,201 @@
+use std::cell::RefCell;
+use std::fmt::{Arguments, Display, Formatter};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Mutex;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+const LEVELS: [(Level, &str); 5] = [
+    (Level::Error, "error"),
+    (Level::Warn, "warn"),
+    (Level::Info, "info"),
+    (Level::Debug, "debug"),
+    (Level::Trace, "trace"),
+];
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (_, name) = LEVELS.iter().find(|(l, _)| l == self).unwrap();
+        write!(f, "{}", name.to_uppercase())
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Level, String> {
+        match LEVELS.iter().find(|(_, name)| name.eq_ignore_ascii_case(s)) {
+            Some((l, _)) => Ok(*l),
+            None => Err(format!("unknown log level {:?}", s)),
+        }
+    }
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+// the highest level of LEVEL and all targets, checked before taking the lock
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+static TARGETS: Mutex<Vec<(String, Level)>> = Mutex::new(Vec::new());
+
+thread_local! {
+    // the records written on this thread while `capture` runs
+    static CAPTURED: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+}
+
+fn update_max_level(targets: &[(String, Level)]) {
+    let max = targets
+        .iter()
+        .map(|(_, l)| *l as u8)
+        .fold(LEVEL.load(Ordering::Relaxed), u8::max);
+    MAX_LEVEL.store(max, Ordering::Relaxed);
+}
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+    update_max_level(&TARGETS.lock().unwrap());
+}
+
+// overrides the level of a single target, e.g. `day16`
+pub fn set_target_level(target: &str, level: Level) {
+    let mut targets = TARGETS.lock().unwrap();
+    targets.retain(|(t, _)| t != target);
+    targets.push((target.to_string(), level));
+    update_max_level(&targets);
+}
+
+pub fn reset() {
+    let mut targets = TARGETS.lock().unwrap();
+    targets.clear();
+    LEVEL.store(Level::Warn as u8, Ordering::Relaxed);
+    update_max_level(&targets);
+}
+
+pub fn enabled(target: &str, level: Level) -> bool {
+    if level as u8 > MAX_LEVEL.load(Ordering::Relaxed) {
+        return false;
+    }
+    let max = match TARGETS.lock().unwrap().iter().find(|(t, _)| t == target) {
+        Some((_, l)) => *l as u8,
+        None => LEVEL.load(Ordering::Relaxed),
+    };
+    level as u8 <= max
+}
+
+// `aoc::y2021::day16::parse` logs to `day16`, the year module is skipped
+pub fn target(module_path: &str) -> &str {
+    let mut parts = module_path.split("::").filter(|x| !is_year_module(x));
+    let first = parts.next().unwrap_or_default();
+    parts.next().unwrap_or(first)
+}
+
+fn is_year_module(name: &str) -> bool {
+    matches!(name.strip_prefix('y'), Some(year) if year.len() == 4 && year.bytes().all(|b| b.is_ascii_digit()))
+}
+
+pub fn write(target: &str, level: Level, args: Arguments) {
+    let line = format!("[{} {}] {}", level, target, args);
+    let line = CAPTURED.with(|c| match c.borrow_mut().as_mut() {
+        Some(lines) => {
+            lines.push(line);
+            None
+        }
+        None => Some(line),
+    });
+    if let Some(line) = line {
+        eprintln!("{}", line);
+    }
+}
+
+// runs `f` and returns the records it writes on this thread instead of printing them
+pub fn capture<T>(f: impl FnOnce() -> T) -> (T, Vec<String>) {
+    let outer = CAPTURED.with(|c| c.replace(Some(vec![])));
+    let result = f();
+    let lines = CAPTURED.with(|c| c.replace(outer)).unwrap_or_default();
+    (result, lines)
+}
+
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {{
+        let target = $crate::util::log::target(module_path!());
+        if $crate::util::log::enabled(target, $level) {
+            $crate::util::log::write(target, $level, format_args!($($arg)+));
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::util::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::util::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::util::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::util::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::log::{
+        capture, enabled, reset, set_level, set_target_level, target, write, Level,
+    };
+
+    #[test]
+    fn test_levels() {
+        assert_eq!(Ok(Level::Debug), "debug".parse());
+        assert_eq!("TRACE", Level::Trace.to_string());
+        assert!("loud".parse::<Level>().is_err());
+        assert_eq!("day16", target("aoc::day16"));
+        assert_eq!("day16", target("aoc::day16::tests"));
+        assert_eq!("aoc", target("aoc"));
+        assert_eq!("day16", target("aoc::y2021::day16::tests"));
+        assert_eq!("util", target("aoc::util::log"));
+
+        // the levels are global, so everything is checked in one test
+        assert!(enabled("day16", Level::Warn));
+        assert!(!enabled("day16", Level::Info));
+
+        set_target_level("day16", Level::Trace);
+        assert!(enabled("day16", Level::Trace));
+        assert!(!enabled("day17", Level::Debug));
+
+        set_level(Level::Debug);
+        assert!(enabled("day17", Level::Debug));
+        assert!(!enabled("day17", Level::Trace));
+
+        set_target_level("day16", Level::Error);
+        assert!(!enabled("day16", Level::Warn));
+
+        reset();
+        assert!(!enabled("day16", Level::Trace));
+        assert!(!enabled("day17", Level::Info));
+    }
+
+    #[test]
+    fn test_capture() {
+        let ((), lines) = capture(|| {
+            write("day1", Level::Info, format_args!("first"));
+            let ((), inner) = capture(|| write("day1", Level::Info, format_args!("inner")));
+            assert_eq!(vec!["[INFO day1] inner"], inner);
+            write("day1", Level::Debug, format_args!("second {}", 2));
+        });
+        assert_eq!(vec!["[INFO day1] first", "[DEBUG day1] second 2"], lines);
+    }
+}
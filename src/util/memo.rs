@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+use std::ops::Index;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Stats {
+    pub hits: usize,
+    pub misses: usize,
+    // the number of cached values
+    pub size: usize,
+}
+
+impl Stats {
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            n => self.hits as f64 / n as f64,
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses, {} cached ({:.1}% hit rate)",
+            self.hits,
+            self.misses,
+            self.size,
+            self.hit_rate() * 100.0
+        )
+    }
+}
+
+// the results of a recursive function by its arguments
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    hits: usize,
+    misses: usize,
+}
+
+impl<K: Eq + Hash, V: Clone> Default for Memo<K, V> {
+    fn default() -> Memo<K, V> {
+        Self::new()
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Memo<K, V> {
+        Memo {
+            cache: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    // the cached value of `key`, computed by `f` on a miss; `f` gets the memo back so it can
+    // recurse through it
+    pub fn get(&mut self, key: K, f: impl FnOnce(&mut Memo<K, V>) -> V) -> V {
+        if let Some(v) = self.cache.get(&key) {
+            self.hits += 1;
+            return v.clone();
+        }
+        self.misses += 1;
+        let v = f(self);
+        self.cache.insert(key, v.clone());
+        v
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn stats(&self) -> Stats {
+        Stats {
+            hits: self.hits,
+            misses: self.misses,
+            size: self.cache.len(),
+        }
+    }
+}
+
+// a dynamic programming table over the keys below `dims`, filled in row-major order so every
+// entry can read the ones before it
+pub struct Table<V, const N: usize> {
+    dims: [usize; N],
+    cells: Vec<V>,
+}
+
+impl<V, const N: usize> Table<V, N> {
+    pub fn build(
+        dims: [usize; N],
+        mut f: impl FnMut(&Table<V, N>, [usize; N]) -> V,
+    ) -> Table<V, N> {
+        let len = dims.iter().product();
+        let mut table = Table {
+            dims,
+            cells: Vec::with_capacity(len),
+        };
+        for i in 0..len {
+            let v = f(&table, table.key(i));
+            table.cells.push(v);
+        }
+        table
+    }
+
+    fn key(&self, mut i: usize) -> [usize; N] {
+        let mut key = [0; N];
+        for (k, d) in key.iter_mut().zip(self.dims).rev() {
+            *k = i % d;
+            i /= d;
+        }
+        key
+    }
+
+    fn index_of(&self, key: [usize; N]) -> usize {
+        key.iter().zip(self.dims).fold(0, |i, (&k, d)| {
+            assert!(k < d, "key {:?} outside of {:?}", key, self.dims);
+            i * d + k
+        })
+    }
+
+    pub fn dims(&self) -> [usize; N] {
+        self.dims
+    }
+
+    // None if the key is outside of the table or not computed yet
+    pub fn get(&self, key: [usize; N]) -> Option<&V> {
+        if key.iter().zip(self.dims).any(|(&k, d)| k >= d) {
+            return None;
+        }
+        self.cells.get(self.index_of(key))
+    }
+}
+
+impl<V, const N: usize> Index<[usize; N]> for Table<V, N> {
+    type Output = V;
+
+    fn index(&self, key: [usize; N]) -> &V {
+        let i = self.index_of(key);
+        self.cells
+            .get(i)
+            .unwrap_or_else(|| panic!("{:?} is not computed yet", key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::memo::{Memo, Stats, Table};
+
+    fn fib(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+        if n < 2 {
+            return n;
+        }
+        memo.get(n, |memo| fib(memo, n - 1) + fib(memo, n - 2))
+    }
+
+    #[test]
+    fn test_memo() {
+        let mut memo = Memo::new();
+        assert_eq!(12586269025, fib(&mut memo, 50));
+        let stats = memo.stats();
+        assert_eq!(49, stats.misses);
+        assert_eq!(47, stats.hits);
+        assert_eq!(49, stats.size);
+
+        assert_eq!(55, fib(&mut memo, 10));
+        assert_eq!(48, memo.stats().hits);
+        assert_eq!(
+            "1 hits, 3 misses, 3 cached (25.0% hit rate)",
+            Stats {
+                hits: 1,
+                misses: 3,
+                size: 3
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_table() {
+        // the lattice paths through a 4x6 grid
+        let paths = Table::build([5, 7], |t, [x, y]| match (x, y) {
+            (0, _) | (_, 0) => 1u64,
+            _ => t[[x - 1, y]] + t[[x, y - 1]],
+        });
+        assert_eq!(210, paths[[4, 6]]);
+        assert_eq!(Some(&15), paths.get([2, 4]));
+        assert_eq!(None, paths.get([5, 0]));
+
+        let fib = Table::build([91], |t, [n]| match n {
+            0 | 1 => n as u64,
+            _ => t[[n - 1]] + t[[n - 2]],
+        });
+        assert_eq!(2880067194370816120, fib[[90]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "not computed yet")]
+    fn test_table_order() {
+        Table::build([3], |t, [n]| if n == 0 { t[[1]] } else { 0 });
+    }
+}
@@ -0,0 +1,10 @@
+pub mod geom;
+pub mod grid;
+pub mod log;
+pub mod memo;
+mod parse;
+pub mod search;
+pub mod sim;
+pub mod snapshot;
+
+pub use parse::*;
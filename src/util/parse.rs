@@ -0,0 +1,451 @@
+use crate::util::grid::Grid;
+use itertools::Itertools;
+use regex::Regex;
+use std::fmt;
+use std::ops::{Deref, Range};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub input: Option<String>,
+    pub line: usize,
+    pub columns: Range<usize>,
+    pub expected: String,
+    pub found: String,
+    pub source: String,
+}
+
+impl std::error::Error for ParseError {}
+
+impl ParseError {
+    // `line` is 1-based, `columns` is the byte range of the offending text within `source`
+    pub fn new(line: usize, source: &str, columns: Range<usize>, expected: &str) -> ParseError {
+        let found = match source.get(columns.clone()) {
+            Some("") | None => "end of line".to_string(),
+            Some(x) => format!("{:?}", x),
+        };
+        ParseError {
+            input: None,
+            line,
+            columns,
+            expected: expected.to_string(),
+            found,
+            source: source.to_string(),
+        }
+    }
+
+    pub fn whole_line(line: usize, source: &str, expected: &str) -> ParseError {
+        Self::new(line, source, 0..source.len(), expected)
+    }
+
+    // locates a byte offset of a multi-line input
+    pub fn at(input: &str, offset: usize, len: usize, expected: &str) -> ParseError {
+        let line_start = input[..offset].rfind('\n').map_or(0, |x| x + 1);
+        let line = input[..line_start].matches('\n').count() + 1;
+        let source = input[line_start..].lines().next().unwrap_or("");
+        let column = offset - line_start;
+        Self::new(
+            line,
+            source,
+            column..(column + len).min(source.len()),
+            expected,
+        )
+    }
+
+    pub fn offset(mut self, lines: usize) -> ParseError {
+        self.line += lines;
+        self
+    }
+
+    pub fn with_input(mut self, name: &str) -> ParseError {
+        self.input = Some(name.to_string());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let line_no = self.line.to_string();
+        let pad = " ".repeat(line_no.len());
+        let start = self
+            .source
+            .get(..self.columns.start)
+            .unwrap_or(&self.source);
+        let start = start.chars().count();
+        let width = self
+            .source
+            .get(self.columns.clone())
+            .map_or(0, |x| x.chars().count())
+            .max(1);
+
+        writeln!(f, "expected {}, found {}", self.expected, self.found)?;
+        writeln!(
+            f,
+            "{}--> {}:{}:{}",
+            pad,
+            self.input.as_deref().unwrap_or("<input>"),
+            self.line,
+            start + 1
+        )?;
+        writeln!(f, "{} |", pad)?;
+        writeln!(f, "{} | {}", line_no, self.source)?;
+        write!(f, "{} | {}{}", pad, " ".repeat(start), "^".repeat(width))
+    }
+}
+
+// attaches the input file name to a parse error, other errors are returned unchanged
+pub fn name_input(e: anyhow::Error, name: &str) -> anyhow::Error {
+    match e.downcast::<ParseError>() {
+        Ok(e) => e.with_input(name).into(),
+        Err(e) => e,
+    }
+}
+
+fn short_type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+pub fn parse_lines<T: FromStr>(s: &str) -> Result<Vec<T>, ParseError> {
+    s.lines()
+        .enumerate()
+        .map(|(i, x)| {
+            x.parse::<T>()
+                .map_err(|_x| ParseError::whole_line(i + 1, x, short_type_name::<T>()))
+        })
+        .try_collect()
+}
+
+// parses the fields of a single line, empty fields are skipped when splitting on spaces
+pub fn parse_split<T: FromStr>(line: usize, s: &str, sep: char) -> Result<Vec<T>, ParseError> {
+    let mut r = vec![];
+    let mut start = 0;
+    for field in s.split(sep) {
+        let columns = start..start + field.len();
+        start = columns.end + sep.len_utf8();
+        if field.is_empty() && sep == ' ' {
+            continue;
+        }
+        match field.trim().parse::<T>() {
+            Ok(x) => r.push(x),
+            Err(_) => return Err(ParseError::new(line, s, columns, short_type_name::<T>())),
+        }
+    }
+    Ok(r)
+}
+
+pub fn parse_digits<T: From<u8>>(line: usize, s: &str) -> Result<Vec<T>, ParseError> {
+    s.char_indices()
+        .map(|(i, c)| match c.to_digit(10) {
+            Some(d) => Ok(T::from(d as u8)),
+            None => Err(ParseError::new(line, s, i..i + c.len_utf8(), "a digit")),
+        })
+        .try_collect()
+}
+
+pub struct RegexLine {
+    pub line: usize,
+    pub source: String,
+    groups: Vec<Range<usize>>,
+    strs: Vec<String>,
+}
+
+impl RegexLine {
+    pub fn parse<T: FromStr>(&self, group: usize) -> Result<T, ParseError> {
+        self.strs[group]
+            .parse::<T>()
+            .map_err(|_| self.error(group, short_type_name::<T>()))
+    }
+
+    pub fn error(&self, group: usize, expected: &str) -> ParseError {
+        ParseError::new(
+            self.line,
+            &self.source,
+            self.groups[group].clone(),
+            expected,
+        )
+    }
+}
+
+impl Deref for RegexLine {
+    type Target = [String];
+
+    fn deref(&self) -> &[String] {
+        &self.strs
+    }
+}
+
+pub fn parse_lines_regex(s: &str, re: &str) -> Result<Vec<RegexLine>, ParseError> {
+    let regex = Regex::new(re).expect("invalid regex");
+    s.lines()
+        .enumerate()
+        .map(|(i, x)| {
+            let captures = regex.captures(x).ok_or_else(|| {
+                ParseError::whole_line(i + 1, x, &format!("a line matching {}", re))
+            })?;
+            let (groups, strs) = captures
+                .iter()
+                .map(|m| match m {
+                    Some(m) => (m.range(), m.as_str().to_string()),
+                    None => (0..0, String::new()),
+                })
+                .unzip();
+            Ok(RegexLine {
+                line: i + 1,
+                source: x.to_string(),
+                groups,
+                strs,
+            })
+        })
+        .try_collect()
+}
+
+pub trait FromCaptures: Sized {
+    fn from_captures(l: &RegexLine) -> Result<Self, ParseError>;
+}
+
+// decodes capture groups 1..=n into a tuple of `FromStr` types
+macro_rules! tuple_from_captures {
+    ($($t:ident $i:tt),+) => {
+        impl<$($t: FromStr),+> FromCaptures for ($($t,)+) {
+            fn from_captures(l: &RegexLine) -> Result<Self, ParseError> {
+                Ok(($(l.parse::<$t>($i)?,)+))
+            }
+        }
+    };
+}
+
+tuple_from_captures!(A 1);
+tuple_from_captures!(A 1, B 2);
+tuple_from_captures!(A 1, B 2, C 3);
+tuple_from_captures!(A 1, B 2, C 3, D 4);
+tuple_from_captures!(A 1, B 2, C 3, D 4, E 5);
+tuple_from_captures!(A 1, B 2, C 3, D 4, E 5, F 6);
+tuple_from_captures!(A 1, B 2, C 3, D 4, E 5, F 6, G 7);
+tuple_from_captures!(A 1, B 2, C 3, D 4, E 5, F 6, G 7, H 8);
+
+pub fn parse_regex<T: FromCaptures>(s: &str, re: &str) -> Result<Vec<T>, ParseError> {
+    parse_lines_regex(s, re)?
+        .iter()
+        .map(T::from_captures)
+        .try_collect()
+}
+
+// a single line of numbers, separated by commas or spaces
+pub fn parse_numbers<T: FromStr>(line: usize, s: &str) -> Result<Vec<T>, ParseError> {
+    let sep = if s.contains(',') { ',' } else { ' ' };
+    parse_split(line, s, sep)
+}
+
+pub fn parse_digit_grid<T: From<u8>>(s: &str) -> Result<Grid<T>, ParseError> {
+    Grid::parse(s, "a digit", |c| c.to_digit(10).map(|d| T::from(d as u8)))
+}
+
+// `key <sep> value` lines, e.g. "start-A" or "CH -> B"
+pub fn parse_rules<K: FromStr, V: FromStr>(s: &str, sep: &str) -> Result<Vec<(K, V)>, ParseError> {
+    s.lines()
+        .enumerate()
+        .map(|(i, l)| {
+            let (k, v) = l.split_once(sep).ok_or_else(|| {
+                ParseError::whole_line(i + 1, l, &format!("a line like `key{}value`", sep))
+            })?;
+            let key = k
+                .trim()
+                .parse::<K>()
+                .map_err(|_| ParseError::new(i + 1, l, 0..k.len(), short_type_name::<K>()))?;
+            let value = v.trim().parse::<V>().map_err(|_| {
+                ParseError::new(
+                    i + 1,
+                    l,
+                    k.len() + sep.len()..l.len(),
+                    short_type_name::<V>(),
+                )
+            })?;
+            Ok((key, value))
+        })
+        .try_collect()
+}
+
+pub struct Section<'a> {
+    // number of lines before the section
+    pub line: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Section<'a> {
+    // runs a parser on the section, errors are reported relative to the whole input
+    pub fn parse<T>(
+        &self,
+        f: impl FnOnce(&'a str) -> Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        f(self.text).map_err(|e| e.offset(self.line))
+    }
+}
+
+// splits the input on blank lines
+pub fn sections(s: &str) -> Vec<Section<'_>> {
+    let mut r = vec![];
+    let mut current: Option<(usize, usize, usize)> = None;
+    let mut offset = 0;
+    for (i, l) in s.split('\n').enumerate() {
+        if l.trim().is_empty() {
+            if let Some((line, start, end)) = current.take() {
+                r.push(Section {
+                    line,
+                    text: &s[start..end],
+                });
+            }
+        } else {
+            let end = offset + l.trim_end_matches('\r').len();
+            match &mut current {
+                Some((_, _, e)) => *e = end,
+                None => current = Some((i, offset, end)),
+            }
+        }
+        offset += l.len() + 1;
+    }
+    if let Some((line, start, end)) = current {
+        r.push(Section {
+            line,
+            text: &s[start..end],
+        });
+    }
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::grid::Grid;
+    use crate::util::{
+        parse_digit_grid, parse_digits, parse_lines, parse_lines_regex, parse_numbers, parse_regex,
+        parse_rules, parse_split, sections, ParseError,
+    };
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(parse_lines::<usize>("123\n456").unwrap(), vec![123, 456]);
+        let e = parse_lines::<usize>("123\n4x6").unwrap_err();
+        assert_eq!((2, "usize"), (e.line, e.expected.as_str()));
+    }
+
+    #[test]
+    fn test_parse_lines_regex() {
+        let strs = parse_lines_regex("abc 123\ncde 345", r"^([a-z]+) ([0-9]+)$").unwrap();
+        assert_eq!(strs[0].to_vec(), vec!["abc 123", "abc", "123"])
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse_lines_regex("abc 123\ncde 34x", r"^([a-z]+) ([0-9]+)$")
+            .err()
+            .unwrap();
+        assert_eq!((2, 0..7), (e.line, e.columns));
+
+        let strs = parse_lines_regex("abc 123\ncde 34x", r"^([a-z]+) ([0-9a-z]+)$").unwrap();
+        let e = strs[1].parse::<u32>(2).unwrap_err().with_input("day0.txt");
+        assert_eq!((2, 4..7), (e.line, e.columns.clone()));
+        assert_eq!(
+            "expected u32, found \"34x\"
+ --> day0.txt:2:5
+  |
+2 | cde 34x
+  |     ^^^",
+            format!("{}", e)
+        );
+    }
+
+    #[test]
+    fn test_parse_split() {
+        assert_eq!(
+            vec![1, 2, 33],
+            parse_split::<i32>(1, " 1  2 33", ' ').unwrap()
+        );
+        assert_eq!(vec![7, 4, 9], parse_split::<i32>(1, "7,4,9", ',').unwrap());
+        let e = parse_split::<i32>(3, "7,x,9", ',').unwrap_err();
+        assert_eq!((3, 2..3), (e.line, e.columns));
+        assert!(parse_split::<i32>(1, "7,,9", ',').is_err());
+    }
+
+    #[test]
+    fn test_parse_digits() {
+        assert_eq!(vec![2, 1, 9], parse_digits::<usize>(1, "219").unwrap());
+        let e = parse_digits::<usize>(4, "21a9").unwrap_err();
+        assert_eq!((4, 2..3), (e.line, e.columns));
+    }
+
+    #[test]
+    fn test_parse_error_at() {
+        let e = ParseError::at("abc\ndefg\nhi", 6, 2, "something");
+        assert_eq!(
+            (2, 2..4, "defg"),
+            (e.line, e.columns.clone(), e.source.as_str())
+        );
+        assert_eq!("\"fg\"", e.found);
+        let e = ParseError::at("abc", 3, 1, "more");
+        assert_eq!("end of line", e.found);
+    }
+
+    #[test]
+    fn test_parse_regex() {
+        let xs = parse_regex::<(String, i32)>("up 3\ndown 12", r"^(\w+) (-?[0-9]+)$").unwrap();
+        assert_eq!(vec![("up".to_string(), 3), ("down".to_string(), 12)], xs);
+        let xs = parse_regex::<(char, char, char)>("AB -> C", r"^(\w)(\w) -> (\w)$").unwrap();
+        assert_eq!(vec![('A', 'B', 'C')], xs);
+        let e = parse_regex::<(String, u8)>("up 3\ndown 300", r"^(\w+) (-?[0-9]+)$").unwrap_err();
+        assert_eq!(
+            (2, 5..8, "u8"),
+            (e.line, e.columns.clone(), e.expected.as_str())
+        );
+    }
+
+    #[test]
+    fn test_parse_numbers() {
+        assert_eq!(
+            vec![3, 4, 3, 1],
+            parse_numbers::<u32>(1, "3,4,3,1").unwrap()
+        );
+        assert_eq!(
+            vec![22, 13, 17],
+            parse_numbers::<u32>(1, "22 13  17").unwrap()
+        );
+        assert_eq!(vec![1, 2], parse_numbers::<u32>(1, "1, 2").unwrap());
+        assert!(parse_numbers::<u32>(1, "1 2,x").is_err());
+    }
+
+    #[test]
+    fn test_parse_digit_grid() {
+        let grid = parse_digit_grid::<u8>("21\n39").unwrap();
+        assert_eq!(Grid::from_rows(vec![vec![2, 1], vec![3, 9]]), grid);
+        let e = parse_digit_grid::<u8>("21\n39\n3").unwrap_err();
+        assert_eq!((3, "2 cells"), (e.line, e.expected.as_str()));
+        let e = parse_digit_grid::<u8>("21\n3x").unwrap_err();
+        assert_eq!((2, 1..2), (e.line, e.columns));
+    }
+
+    #[test]
+    fn test_parse_rules() {
+        let rules = parse_rules::<String, char>("CH -> B\nHH -> N", " -> ").unwrap();
+        assert_eq!(
+            vec![("CH".to_string(), 'B'), ("HH".to_string(), 'N')],
+            rules
+        );
+        let e = parse_rules::<String, char>("CH -> B\nHH -> NN", " -> ").unwrap_err();
+        assert_eq!((2, 6..8), (e.line, e.columns));
+        let e = parse_rules::<String, String>("start-A\nb", "-").unwrap_err();
+        assert_eq!(
+            (2, "a line like `key-value`"),
+            (e.line, e.expected.as_str())
+        );
+    }
+
+    #[test]
+    fn test_sections() {
+        let s = sections("\n1\n2\n\n\n3\n\nx\n");
+        assert_eq!(
+            vec![(1, "1\n2"), (5, "3"), (7, "x")],
+            s.iter().map(|x| (x.line, x.text)).collect::<Vec<_>>()
+        );
+        let e = s[2].parse(parse_lines::<u32>).unwrap_err();
+        assert_eq!(8, e.line);
+    }
+}
@@ -0,0 +1,428 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+// a graph given by the successors of its nodes and the costs of the edges to them
+pub trait Successors {
+    type Node: Clone + Eq + Hash;
+
+    fn successors(&self, node: &Self::Node) -> Vec<(Self::Node, u64)>;
+
+    // a lower bound of the cost to reach a goal, A* only finds the cheapest path if it never
+    // overestimates
+    fn heuristic(&self, _node: &Self::Node) -> u64 {
+        0
+    }
+}
+
+pub struct FromFn<N, F> {
+    f: F,
+    node: PhantomData<N>,
+}
+
+// a graph from a closure returning the successors of a node
+pub fn from_fn<N, F>(f: F) -> FromFn<N, F>
+where
+    N: Clone + Eq + Hash,
+    F: Fn(&N) -> Vec<(N, u64)>,
+{
+    FromFn {
+        f,
+        node: PhantomData,
+    }
+}
+
+impl<N, F> Successors for FromFn<N, F>
+where
+    N: Clone + Eq + Hash,
+    F: Fn(&N) -> Vec<(N, u64)>,
+{
+    type Node = N;
+
+    fn successors(&self, node: &N) -> Vec<(N, u64)> {
+        (self.f)(node)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Path<N> {
+    pub cost: u64,
+    // from the start to the goal, both included
+    pub nodes: Vec<N>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Stats {
+    // nodes whose successors were looked at
+    pub expanded: usize,
+    // the most nodes waiting to be expanded at the same time
+    pub frontier_peak: usize,
+}
+
+impl Stats {
+    fn frontier(&mut self, len: usize) {
+        self.frontier_peak = self.frontier_peak.max(len);
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Search<N> {
+    pub path: Option<Path<N>>,
+    pub stats: Stats,
+}
+
+impl<N> Search<N> {
+    pub fn cost(&self) -> Option<u64> {
+        self.path.as_ref().map(|p| p.cost)
+    }
+}
+
+// the nodes seen so far with the cheapest known cost and the node it was reached from
+struct Visited<N> {
+    nodes: Vec<(N, usize, u64)>,
+    index: HashMap<N, usize>,
+}
+
+impl<N: Clone + Eq + Hash> Visited<N> {
+    fn new(start: N) -> Visited<N> {
+        Visited {
+            nodes: vec![(start.clone(), usize::MAX, 0)],
+            index: HashMap::from([(start, 0)]),
+        }
+    }
+
+    fn cost(&self, node: &N) -> Option<u64> {
+        self.index.get(node).map(|&i| self.nodes[i].2)
+    }
+
+    // records a cheaper way to the node, returns its index if it was cheaper
+    fn relax(&mut self, node: N, parent: usize, cost: u64) -> Option<usize> {
+        match self.index.entry(node) {
+            Entry::Vacant(e) => {
+                let i = self.nodes.len();
+                self.nodes.push((e.key().clone(), parent, cost));
+                e.insert(i);
+                Some(i)
+            }
+            Entry::Occupied(e) => {
+                let i = *e.get();
+                if cost >= self.nodes[i].2 {
+                    return None;
+                }
+                self.nodes[i].1 = parent;
+                self.nodes[i].2 = cost;
+                Some(i)
+            }
+        }
+    }
+
+    // the nodes from the start to the node with index `i`
+    fn path(&self, mut i: usize) -> Vec<N> {
+        let mut nodes = vec![];
+        while i != usize::MAX {
+            nodes.push(self.nodes[i].0.clone());
+            i = self.nodes[i].1;
+        }
+        nodes.reverse();
+        nodes
+    }
+
+    fn found(&self, i: usize, stats: Stats) -> Search<N> {
+        Search {
+            path: Some(Path {
+                cost: self.nodes[i].2,
+                nodes: self.path(i),
+            }),
+            stats,
+        }
+    }
+}
+
+// ignores the costs of the edges, the cost of the path is its number of edges
+pub fn bfs<G: Successors>(
+    graph: &G,
+    start: G::Node,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+) -> Search<G::Node> {
+    let mut stats = Stats::default();
+    let mut visited = Visited::new(start);
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(i) = queue.pop_front() {
+        let (node, _, depth) = visited.nodes[i].clone();
+        if is_goal(&node) {
+            return visited.found(i, stats);
+        }
+        stats.expanded += 1;
+        for (next, _) in graph.successors(&node) {
+            if visited.cost(&next).is_none() {
+                queue.push_back(visited.relax(next, i, depth + 1).unwrap());
+            }
+        }
+        stats.frontier(queue.len());
+    }
+
+    Search { path: None, stats }
+}
+
+pub fn dijkstra<G: Successors>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl FnMut(&G::Node) -> bool,
+) -> Search<G::Node> {
+    best_first(graph, start, is_goal, |_| 0)
+}
+
+// dijkstra guided by `Successors::heuristic`
+pub fn astar<G: Successors>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl FnMut(&G::Node) -> bool,
+) -> Search<G::Node> {
+    best_first(graph, start, is_goal, |n| graph.heuristic(n))
+}
+
+fn best_first<G: Successors>(
+    graph: &G,
+    start: G::Node,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+    heuristic: impl Fn(&G::Node) -> u64,
+) -> Search<G::Node> {
+    let mut stats = Stats::default();
+    // the lowest estimate first, of those the node that is furthest from the start
+    let mut heap = BinaryHeap::from([(Reverse(heuristic(&start)), 0, 0)]);
+    let mut visited = Visited::new(start);
+
+    while let Some((_, cost, i)) = heap.pop() {
+        // a cheaper way to the node was found after this one was queued
+        if cost > visited.nodes[i].2 {
+            continue;
+        }
+        let node = visited.nodes[i].0.clone();
+        if is_goal(&node) {
+            return visited.found(i, stats);
+        }
+        stats.expanded += 1;
+        for (next, c) in graph.successors(&node) {
+            let h = heuristic(&next);
+            if let Some(j) = visited.relax(next, i, cost + c) {
+                heap.push((Reverse(cost + c + h), cost + c, j));
+            }
+        }
+        stats.frontier(heap.len());
+    }
+
+    Search { path: None, stats }
+}
+
+// dijkstra from both ends, `backward` has the edges of `forward` reversed
+pub fn bidirectional<G, R>(
+    forward: &G,
+    backward: &R,
+    start: G::Node,
+    goal: G::Node,
+) -> Search<G::Node>
+where
+    G: Successors,
+    R: Successors<Node = G::Node>,
+{
+    let mut stats = Stats::default();
+    // the cheapest path through a node seen from both sides so far
+    let mut best = (start == goal).then_some((0, 0, 0));
+    let mut sides = [
+        (Visited::new(start), BinaryHeap::from([Reverse((0, 0))])),
+        (Visited::new(goal), BinaryHeap::from([Reverse((0, 0))])),
+    ];
+
+    while let (Some(Reverse((f, _))), Some(Reverse((b, _)))) =
+        (sides[0].1.peek(), sides[1].1.peek())
+    {
+        // no path through the nodes that are left can be cheaper
+        if matches!(best, Some((cost, _, _)) if f + b >= cost) {
+            break;
+        }
+
+        let side = if sides[0].1.len() <= sides[1].1.len() {
+            0
+        } else {
+            1
+        };
+        let (first, second) = sides.split_at_mut(1);
+        let ((visited, heap), (other, _)) = match side {
+            0 => (&mut first[0], &second[0]),
+            _ => (&mut second[0], &first[0]),
+        };
+
+        let Reverse((cost, i)) = heap.pop().unwrap();
+        if cost > visited.nodes[i].2 {
+            continue;
+        }
+        stats.expanded += 1;
+        let node = visited.nodes[i].0.clone();
+        let successors = match side {
+            0 => forward.successors(&node),
+            _ => backward.successors(&node),
+        };
+        for (next, c) in successors {
+            if let Some(j) = visited.relax(next.clone(), i, cost + c) {
+                heap.push(Reverse((cost + c, j)));
+            }
+            if let Some(&k) = other.index.get(&next) {
+                let j = visited.index[&next];
+                let total = visited.nodes[j].2 + other.nodes[k].2;
+                if best.is_none_or(|(cost, _, _)| total < cost) {
+                    best = Some(match side {
+                        0 => (total, j, k),
+                        _ => (total, k, j),
+                    });
+                }
+            }
+        }
+        stats.frontier(sides[0].1.len() + sides[1].1.len());
+    }
+
+    let path = best.map(|(cost, f, b)| {
+        let mut nodes = sides[0].0.path(f);
+        let mut rest = sides[1].0.path(b);
+        rest.pop();
+        rest.reverse();
+        nodes.extend(rest);
+        Path { cost, nodes }
+    });
+    Search { path, stats }
+}
+
+// the number of different paths from the start to a goal, paths end at the first goal they
+// reach and the graph must not have cycles
+pub fn count_paths<G: Successors>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl Fn(&G::Node) -> bool,
+) -> (u64, Stats) {
+    fn count<G: Successors>(
+        graph: &G,
+        node: G::Node,
+        is_goal: &impl Fn(&G::Node) -> bool,
+        memo: &mut HashMap<G::Node, u64>,
+        stats: &mut Stats,
+        depth: usize,
+    ) -> u64 {
+        if is_goal(&node) {
+            return 1;
+        }
+        if let Some(&n) = memo.get(&node) {
+            return n;
+        }
+        stats.expanded += 1;
+        stats.frontier(depth);
+        let n = graph
+            .successors(&node)
+            .into_iter()
+            .map(|(next, _)| count(graph, next, is_goal, memo, stats, depth + 1))
+            .sum();
+        memo.insert(node, n);
+        n
+    }
+
+    let mut stats = Stats::default();
+    let n = count(graph, start, &is_goal, &mut HashMap::new(), &mut stats, 1);
+    (n, stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::grid::{Grid, Pos};
+    use crate::util::search::{
+        astar, bfs, bidirectional, count_paths, dijkstra, from_fn, Successors,
+    };
+    use itertools::Itertools;
+
+    struct Maze(Grid<u8>);
+
+    impl Successors for Maze {
+        type Node = Pos;
+
+        fn successors(&self, &p: &Pos) -> Vec<(Pos, u64)> {
+            self.0
+                .orthogonal(p)
+                .filter(|&n| self.0[n] != b'#')
+                .map(|n| (n, (self.0[n] - b'0') as u64))
+                .collect()
+        }
+
+        fn heuristic(&self, &(x, y): &Pos) -> u64 {
+            ((self.0.width() - 1 - x) + (self.0.height() - 1 - y)) as u64
+        }
+    }
+
+    fn maze() -> Maze {
+        Maze(Grid::parse("1191\n1#11\n1#11\n1111", "a cell", |c| Some(c as u8)).unwrap())
+    }
+
+    #[test]
+    fn test_bfs() {
+        let search = bfs(&maze(), (0, 0), |&p| p == (2, 1));
+        let path = search.path.unwrap();
+        assert_eq!(3, path.cost);
+        assert_eq!(vec![(0, 0), (1, 0), (2, 0), (2, 1)], path.nodes);
+        assert!(search.stats.expanded > 0);
+        assert!(bfs(&maze(), (0, 0), |&p| p == (1, 1)).path.is_none());
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let goal = |&p: &Pos| p == (2, 1);
+        let path = dijkstra(&maze(), (0, 0), goal).path.unwrap();
+        assert_eq!(7, path.cost);
+        assert_eq!(
+            vec![
+                (0, 0),
+                (0, 1),
+                (0, 2),
+                (0, 3),
+                (1, 3),
+                (2, 3),
+                (2, 2),
+                (2, 1)
+            ],
+            path.nodes
+        );
+
+        let goal = |&p: &Pos| p == (3, 3);
+        let slow = dijkstra(&maze(), (0, 0), goal);
+        let fast = astar(&maze(), (0, 0), goal);
+        assert_eq!(Some(6), slow.cost());
+        assert_eq!(slow.cost(), fast.cost());
+        assert!(fast.stats.expanded < slow.stats.expanded);
+        assert!(astar(&maze(), (0, 0), |&p| p == (1, 1)).path.is_none());
+    }
+
+    #[test]
+    fn test_bidirectional() {
+        // a line of nodes where going left costs twice as much
+        let forward = from_fn(|&x: &i64| vec![(x - 1, 2), (x + 1, 1)]);
+        let backward = from_fn(|&x: &i64| vec![(x + 1, 2), (x - 1, 1)]);
+        let search = bidirectional(&forward, &backward, 0, 5);
+        assert_eq!(Some(5), search.cost());
+        assert_eq!((0..=5).collect_vec(), search.path.unwrap().nodes);
+        let search = bidirectional(&forward, &backward, 3, 0);
+        assert_eq!(vec![3, 2, 1, 0], search.path.clone().unwrap().nodes);
+        assert_eq!(Some(6), search.cost());
+        assert_eq!(Some(0), bidirectional(&forward, &backward, 2, 2).cost());
+        assert_eq!(
+            dijkstra(&forward, -4, |&x| x == 7).cost(),
+            bidirectional(&forward, &backward, -4, 7).cost()
+        );
+    }
+
+    #[test]
+    fn test_count_paths() {
+        // the ways to climb 10 steps taking 1 or 2 at a time
+        let steps = from_fn(|&x: &u32| vec![(x + 1, 1), (x + 2, 1)]);
+        let (n, stats) = count_paths(&steps, 0, |&x| x >= 10);
+        assert_eq!(89 + 55, n);
+        assert_eq!(10, stats.expanded);
+    }
+}
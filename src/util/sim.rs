@@ -0,0 +1,265 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+pub trait Simulation {
+    fn step(&mut self);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    // the first step whose state repeats
+    pub start: usize,
+    // the steps until it repeats, 1 for a fixed point
+    pub len: usize,
+}
+
+impl Cycle {
+    // the earliest step with the same state as step `n`
+    pub fn reduce(&self, n: usize) -> usize {
+        match n > self.start {
+            true => self.start + (n - self.start) % self.len,
+            false => n,
+        }
+    }
+}
+
+// a state with its hash, so most comparisons don't have to look at the whole state
+struct Hashed<S> {
+    hash: u64,
+    state: S,
+}
+
+impl<S: Hash + Eq> Hashed<S> {
+    fn new(state: S) -> Hashed<S> {
+        let mut hasher = DefaultHasher::new();
+        state.hash(&mut hasher);
+        Hashed {
+            hash: hasher.finish(),
+            state,
+        }
+    }
+
+    fn same(&self, o: &Hashed<S>) -> bool {
+        self.hash == o.hash && self.state == o.state
+    }
+}
+
+// drives a simulation and counts its steps
+pub struct Sim<S> {
+    state: S,
+    steps: usize,
+}
+
+impl<S: Simulation> Sim<S> {
+    pub fn new(state: S) -> Sim<S> {
+        Sim { state, steps: 0 }
+    }
+
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    pub fn into_state(self) -> S {
+        self.state
+    }
+
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn step(&mut self) {
+        self.state.step();
+        self.steps += 1;
+    }
+
+    pub fn run(&mut self, n: usize) {
+        (0..n).for_each(|_| self.step());
+    }
+
+    // steps until `f` holds for the state, returns the step it holds at or None if it doesn't
+    // within `limit` steps
+    pub fn run_until(&mut self, limit: usize, mut f: impl FnMut(&S) -> bool) -> Option<usize> {
+        for _ in 0..limit {
+            if f(&self.state) {
+                return Some(self.steps);
+            }
+            self.step();
+        }
+        Some(self.steps).filter(|_| f(&self.state))
+    }
+
+    // like `run_until`, but takes at least one step so the current state is not checked
+    pub fn run_until_after_step(
+        &mut self,
+        limit: usize,
+        f: impl FnMut(&S) -> bool,
+    ) -> Option<usize> {
+        if limit == 0 {
+            return None;
+        }
+        self.step();
+        self.run_until(limit - 1, f)
+    }
+}
+
+impl<S: Simulation + Clone + Hash + Eq> Sim<S> {
+    // steps until a step no longer changes the state, returns the step of that state or None if
+    // there is none within `limit` steps
+    pub fn run_to_fixed_point(&mut self, limit: usize) -> Option<usize> {
+        let mut prev = Hashed::new(self.state.clone());
+        for _ in 0..limit {
+            self.step();
+            let next = Hashed::new(self.state.clone());
+            if next.same(&prev) {
+                self.steps -= 1;
+                return Some(self.steps);
+            }
+            prev = next;
+        }
+        None
+    }
+
+    // Brent's algorithm from the current state, None if no state repeats within `limit` steps
+    pub fn find_cycle(&self, limit: usize) -> Option<Cycle> {
+        let advance = |s: &Hashed<S>| {
+            let mut next = s.state.clone();
+            next.step();
+            Hashed::new(next)
+        };
+
+        let start = Hashed::new(self.state.clone());
+        let mut tortoise = Hashed::new(self.state.clone());
+        let mut hare = advance(&start);
+        let (mut power, mut len) = (1, 1);
+        while !tortoise.same(&hare) {
+            if power == len {
+                tortoise = Hashed::new(hare.state.clone());
+                power *= 2;
+                len = 0;
+            }
+            // the tortoise sits at step power - 1, a cycle within `limit` steps is found before
+            // power passes twice the limit
+            if power > limit.saturating_mul(2) {
+                return None;
+            }
+            hare = advance(&hare);
+            len += 1;
+        }
+
+        // a hare `len` steps ahead meets the tortoise at the start of the cycle
+        let mut tortoise = start;
+        let mut hare = Hashed::new(self.state.clone());
+        for _ in 0..len {
+            hare = advance(&hare);
+        }
+        let mut mu = 0;
+        while !tortoise.same(&hare) {
+            tortoise = advance(&tortoise);
+            hare = advance(&hare);
+            mu += 1;
+        }
+
+        Some(Cycle {
+            start: self.steps + mu,
+            len,
+        })
+    }
+
+    // moves to step `n`, skipping over whole cycles instead of simulating them
+    pub fn run_to(&mut self, n: usize) {
+        assert!(n >= self.steps, "cannot go back to step {}", n);
+        let target = match self.find_cycle(n - self.steps) {
+            Some(cycle) => cycle.reduce(n),
+            None => n,
+        };
+        self.run(target - self.steps);
+        self.steps = n;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::sim::{Cycle, Sim, Simulation};
+
+    // x -> x² + 1 mod m, which runs into a cycle for every start
+    #[derive(Clone, Hash, PartialEq, Eq)]
+    struct Square(u64, u64);
+
+    impl Simulation for Square {
+        fn step(&mut self) {
+            self.0 = (self.0 * self.0 + 1) % self.1;
+        }
+    }
+
+    // counts down to zero and stays there
+    #[derive(Clone, Hash, PartialEq, Eq)]
+    struct Countdown(u32);
+
+    impl Simulation for Countdown {
+        fn step(&mut self) {
+            self.0 = self.0.saturating_sub(1);
+        }
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        // compared with the first repeated value of the whole sequence
+        for (m, start) in [(255, 3), (1000, 7), (97, 0)] {
+            let sim = Sim::new(Square(start, m));
+            let cycle = sim.find_cycle(10000).unwrap();
+
+            let mut xs = vec![start];
+            let mut x = Square(start, m);
+            while !xs[..xs.len() - 1].contains(xs.last().unwrap()) {
+                x.step();
+                xs.push(x.0);
+            }
+            let first = xs.iter().position(|y| y == xs.last().unwrap()).unwrap();
+            assert_eq!(
+                Cycle {
+                    start: first,
+                    len: xs.len() - 1 - first
+                },
+                cycle
+            );
+        }
+
+        assert_eq!(None, Sim::new(Countdown(50)).find_cycle(10));
+        let mut sim = Sim::new(Countdown(50));
+        sim.run(5);
+        assert_eq!(Some(Cycle { start: 50, len: 1 }), sim.find_cycle(100));
+    }
+
+    #[test]
+    fn test_run_to() {
+        let mut brute = Sim::new(Square(7, 1000));
+        brute.run(1234);
+
+        let mut sim = Sim::new(Square(7, 1000));
+        sim.run(3);
+        sim.run_to(1234);
+        assert_eq!(1234, sim.steps());
+        assert_eq!(brute.state().0, sim.state().0);
+
+        let mut sim = Sim::new(Countdown(5));
+        sim.run_to(1_000_000_000);
+        assert_eq!(0, sim.state().0);
+    }
+
+    #[test]
+    fn test_fixed_point() {
+        let mut sim = Sim::new(Countdown(12));
+        assert_eq!(Some(12), sim.run_to_fixed_point(100));
+        assert_eq!(12, sim.steps());
+        assert_eq!(None, Sim::new(Square(7, 1000)).run_to_fixed_point(100));
+
+        let mut sim = Sim::new(Countdown(12));
+        assert_eq!(Some(4), sim.run_until(10, |x| x.0 == 8));
+        // already holds, so only the second one steps
+        assert_eq!(Some(4), sim.run_until(10, |x| x.0 <= 8));
+        assert_eq!(Some(5), sim.run_until_after_step(10, |x| x.0 <= 8));
+        assert_eq!(None, sim.run_until(6, |x| x.0 == 0));
+        assert_eq!(Some(12), sim.run_until(1, |x| x.0 == 0));
+        assert_eq!(None, sim.run_until_after_step(0, |x| x.0 == 0));
+    }
+}
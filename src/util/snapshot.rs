@@ -0,0 +1,205 @@
+use itertools::Itertools;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// set to 1 to rewrite all snapshots that are checked instead of comparing them
+pub const UPDATE_VAR: &str = "AOC_UPDATE_SNAPSHOTS";
+
+pub fn dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("snapshots")
+}
+
+thread_local! {
+    static COUNTERS: RefCell<HashMap<String, usize>> = RefCell::new(HashMap::new());
+}
+
+// `aoc::y2021::day20::tests::test_display::f` becomes `y2021__day20__test_display`,
+// further snapshots in the same test get a `-2`, `-3`, .. suffix
+pub fn name(function: &str) -> String {
+    let base = function
+        .trim_end_matches("::f")
+        .split("::")
+        .skip(1)
+        .filter(|x| *x != "tests")
+        .join("__");
+    let n = COUNTERS.with(|c| {
+        let mut counters = c.borrow_mut();
+        let n = counters.entry(base.clone()).or_insert(0);
+        *n += 1;
+        *n
+    });
+    match n {
+        1 => base,
+        n => format!("{}-{}", base, n),
+    }
+}
+
+fn render(source: &str, expression: &str, value: &str) -> String {
+    format!(
+        "---\nsource: {}\nexpression: {}\n---\n{}\n",
+        source,
+        expression,
+        value.trim_end_matches('\n')
+    )
+}
+
+// the value stored in a snapshot file, without the header
+fn value(snapshot: &str) -> &str {
+    let body = match snapshot
+        .strip_prefix("---\n")
+        .and_then(|s| s.split_once("\n---\n"))
+    {
+        Some((_, body)) => body,
+        None => snapshot,
+    };
+    body.trim_end_matches('\n')
+}
+
+// a line diff of the longest common subsequence, `-` lines are only in the snapshot
+pub fn diff(expected: &str, actual: &str) -> String {
+    let (a, b) = (expected.lines().collect_vec(), actual.lines().collect_vec());
+    let mut lcs = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = match a[i] == b[j] {
+                true => lcs[i + 1][j + 1] + 1,
+                false => lcs[i + 1][j].max(lcs[i][j + 1]),
+            };
+        }
+    }
+
+    let mut out = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            out.push(format!("  {}", a[i]));
+            i += 1;
+            j += 1;
+        } else if j == b.len() || (i < a.len() && lcs[i + 1][j] >= lcs[i][j + 1]) {
+            out.push(format!("- {}", a[i]));
+            i += 1;
+        } else {
+            out.push(format!("+ {}", b[j]));
+            j += 1;
+        }
+    }
+    out.join("\n")
+}
+
+// compares `actual` with the snapshot at `path`, or writes it in update mode
+pub fn check(
+    path: &Path,
+    source: &str,
+    expression: &str,
+    actual: &str,
+    update: bool,
+) -> Result<(), String> {
+    let existing = fs::read_to_string(path).ok();
+    match existing {
+        Some(snapshot) if !update => {
+            let expected = value(&snapshot);
+            let actual = actual.trim_end_matches('\n');
+            match expected == actual {
+                true => Ok(()),
+                false => Err(format!(
+                    "snapshot {} does not match, rerun with {}=1 to update it\n{}",
+                    path.display(),
+                    UPDATE_VAR,
+                    diff(expected, actual)
+                )),
+            }
+        }
+        None if !update => Err(format!(
+            "snapshot {} is missing, rerun with {}=1 to write it",
+            path.display(),
+            UPDATE_VAR
+        )),
+        _ => {
+            let write = || -> std::io::Result<()> {
+                fs::create_dir_all(path.parent().unwrap_or_else(|| Path::new(".")))?;
+                fs::write(path, render(source, expression, actual))
+            };
+            write().map_err(|e| format!("Unable to write {}: {}", path.display(), e))
+        }
+    }
+}
+
+#[track_caller]
+pub fn assert(function: &str, source: &str, expression: &str, actual: &str) {
+    let path = dir().join(format!("{}.snap", name(function)));
+    let update = std::env::var(UPDATE_VAR).as_deref() == Ok("1");
+    if let Err(e) = check(&path, source, expression, actual, update) {
+        panic!("{}", e);
+    }
+}
+
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($value:expr) => {{
+        fn f() {}
+        fn name_of<T>(_: T) -> &'static str {
+            std::any::type_name::<T>()
+        }
+        $crate::util::snapshot::assert(
+            name_of(f),
+            file!(),
+            stringify!($value),
+            &format!("{}", $value),
+        )
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::inputs::tests::temp_dir;
+    use crate::util::snapshot::{check, diff, name};
+
+    #[test]
+    fn test_name() {
+        assert_eq!(
+            "day20__test_display",
+            name("aoc::day20::tests::test_display::f")
+        );
+        assert_eq!(
+            "day20__test_display-2",
+            name("aoc::day20::tests::test_display::f")
+        );
+        assert_eq!(
+            "y2021__day20__test_display",
+            name("aoc::y2021::day20::tests::test_display::f")
+        );
+        assert_eq!(
+            "util__grid__test_grid",
+            name("aoc::util::grid::tests::test_grid::f")
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let path = temp_dir("snapshot").join("day1__test.snap");
+        let e = check(&path, "src/day1.rs", "x", "#.\n.#\n", false).unwrap_err();
+        assert!(e.contains("is missing"), "{}", e);
+        assert!(!path.exists());
+
+        assert_eq!(Ok(()), check(&path, "src/day1.rs", "x", "#.\n.#\n", true));
+        assert_eq!(
+            "---\nsource: src/day1.rs\nexpression: x\n---\n#.\n.#\n",
+            std::fs::read_to_string(&path).unwrap()
+        );
+        assert_eq!(Ok(()), check(&path, "src/day1.rs", "x", "#.\n.#", false));
+
+        let e = check(&path, "src/day1.rs", "x", "#.\n##", false).unwrap_err();
+        assert!(e.ends_with("  #.\n- .#\n+ ##"), "{}", e);
+
+        assert_eq!(Ok(()), check(&path, "src/day1.rs", "x", "#.\n##", true));
+        assert_eq!(Ok(()), check(&path, "src/day1.rs", "x", "#.\n##", false));
+    }
+
+    #[test]
+    fn test_diff() {
+        assert_eq!("  a\n- b\n+ x\n  c\n+ d", diff("a\nb\nc", "a\nx\nc\nd"));
+        assert_eq!("- a", diff("a", ""));
+    }
+}
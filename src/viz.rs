@@ -0,0 +1,386 @@
+use crate::util::grid::Grid;
+use crate::y2021::{self, day11, day20, day25, day9};
+use anyhow::{bail, Result};
+use itertools::Itertools;
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+// a cell of a frame, `level` picks a colour from cold (0) to hot (9)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cell {
+    pub glyph: char,
+    pub level: Option<u8>,
+}
+
+impl Cell {
+    pub fn plain(glyph: char) -> Cell {
+        Cell { glyph, level: None }
+    }
+
+    pub fn heat(glyph: char, level: u8) -> Cell {
+        Cell {
+            glyph,
+            level: Some(level.min(9)),
+        }
+    }
+
+    // a digit coloured by its value
+    pub fn digit(value: u8) -> Cell {
+        Cell::heat(char::from_digit(value as u32 % 10, 10).unwrap(), value)
+    }
+}
+
+pub struct Frame {
+    pub caption: String,
+    pub cells: Grid<Cell>,
+}
+
+pub type Frames = Box<dyn Iterator<Item = Frame>>;
+
+// turns an input into the frames of its simulation
+pub type Visualizer = fn(&str) -> Result<Frames>;
+
+pub fn visualizers() -> Vec<(u32, u32, Visualizer)> {
+    vec![
+        (y2021::YEAR, 9, day9::frames),
+        (y2021::YEAR, 11, day11::frames),
+        (y2021::YEAR, 20, day20::frames),
+        (y2021::YEAR, 25, day25::frames),
+    ]
+}
+
+pub fn visualizer(year: u32, day: u32) -> Result<Visualizer> {
+    match visualizers()
+        .into_iter()
+        .find(|&(y, d, _)| y == year && d == day)
+    {
+        Some((_, _, v)) => Ok(v),
+        None => bail!(
+            "No visualization for {} day {}, available are days {}",
+            year,
+            day,
+            visualizers()
+                .iter()
+                .filter(|(y, _, _)| *y == year)
+                .map(|(_, d, _)| d)
+                .join(", ")
+        ),
+    }
+}
+
+// 256-colour palette from dark blue over green to bright yellow
+const HEAT: [u8; 10] = [17, 18, 25, 31, 37, 71, 107, 143, 179, 226];
+
+// the rows of a frame, cut to `width` x `height` cells; colours are ANSI escapes
+pub fn render(frame: &Frame, color: bool, width: usize, height: usize) -> String {
+    let mut s = String::new();
+    for row in frame.cells.rows().take(height) {
+        let mut current = None;
+        for cell in row.iter().take(width) {
+            if color && cell.level != current {
+                match cell.level {
+                    Some(l) => s += &format!("\x1b[38;5;{}m", HEAT[l as usize]),
+                    None => s += "\x1b[0m",
+                }
+                current = cell.level;
+            }
+            s.push(cell.glyph);
+        }
+        if color && current.is_some() {
+            s += "\x1b[0m";
+        }
+        s.push('\n');
+    }
+    s
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Options {
+    pub fps: f64,
+    // the most frames shown
+    pub frames: usize,
+    // dump the frames even on a terminal
+    pub plain: bool,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options {
+            fps: 10.0,
+            frames: 100,
+            plain: false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Action {
+    Quit,
+    // show the next frame now
+    Step,
+    Redraw,
+    None,
+}
+
+// what the keys change while playing
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Controls {
+    pub paused: bool,
+    pub fps: f64,
+}
+
+impl Controls {
+    pub fn key(&mut self, key: u8) -> Action {
+        match key {
+            b'q' | 3 => Action::Quit,
+            b' ' | b'p' => {
+                self.paused = !self.paused;
+                Action::Redraw
+            }
+            b'n' | b's' | b'.' => {
+                self.paused = true;
+                Action::Step
+            }
+            b'+' | b'=' => {
+                self.fps = (self.fps * 2.0).min(240.0);
+                Action::Redraw
+            }
+            b'-' => {
+                self.fps = (self.fps / 2.0).max(0.25);
+                Action::Redraw
+            }
+            _ => Action::None,
+        }
+    }
+
+    pub fn delay(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.fps)
+    }
+}
+
+pub fn is_terminal() -> bool {
+    unsafe { libc::isatty(libc::STDIN_FILENO) == 1 && libc::isatty(libc::STDOUT_FILENO) == 1 }
+}
+
+// plays the frames on a raw terminal, or prints them one after another if there is none
+pub fn show(frames: Frames, options: &Options) -> Result<()> {
+    let frames = frames.take(options.frames);
+    match !options.plain && is_terminal() {
+        true => play(frames, options),
+        false => dump(frames, &mut io::stdout().lock()),
+    }
+}
+
+pub fn dump(frames: impl Iterator<Item = Frame>, out: &mut impl Write) -> Result<()> {
+    for (i, frame) in frames.enumerate() {
+        writeln!(out, "frame {}: {}", i, frame.caption)?;
+        writeln!(out, "{}", render(&frame, false, usize::MAX, usize::MAX))?;
+    }
+    Ok(())
+}
+
+fn play(mut frames: impl Iterator<Item = Frame>, options: &Options) -> Result<()> {
+    let _raw = RawTerminal::enable()?;
+    let mut out = io::stdout().lock();
+    let mut controls = Controls {
+        paused: false,
+        fps: options.fps,
+    };
+
+    let mut current = match frames.next() {
+        Some(x) => x,
+        None => return Ok(()),
+    };
+    let mut index = 0;
+    let mut finished = false;
+    let mut next_at = Instant::now() + controls.delay();
+
+    loop {
+        let (width, height) = terminal_size().unwrap_or((80, 24));
+        let status = format!(
+            "frame {}{}  {}  {} fps  [space] pause  [n] step  [+/-] speed  [q] quit",
+            index,
+            if finished { " (last)" } else { "" },
+            current.caption,
+            controls.fps,
+        );
+        // redraws in place and clears whatever the previous frame left behind
+        write!(
+            out,
+            "\x1b[H{}{}\x1b[K\x1b[J",
+            render(&current, true, width, height.saturating_sub(1)),
+            status.chars().take(width).collect::<String>()
+        )?;
+        out.flush()?;
+
+        let timeout = match controls.paused || finished {
+            true => None,
+            false => Some(next_at.saturating_duration_since(Instant::now())),
+        };
+        let advance = match read_key(timeout)? {
+            Some(key) => match controls.key(key) {
+                Action::Quit => return Ok(()),
+                Action::Step => true,
+                Action::Redraw | Action::None => false,
+            },
+            None => true,
+        };
+
+        if advance && !finished {
+            match frames.next() {
+                Some(x) => {
+                    current = x;
+                    index += 1;
+                }
+                None => finished = true,
+            }
+            next_at = Instant::now() + controls.delay();
+        }
+    }
+}
+
+// puts the terminal into raw mode on the alternate screen until dropped
+struct RawTerminal {
+    original: libc::termios,
+}
+
+impl RawTerminal {
+    fn enable() -> io::Result<RawTerminal> {
+        unsafe {
+            let mut termios: libc::termios = std::mem::zeroed();
+            if libc::tcgetattr(libc::STDIN_FILENO, &mut termios) < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let original = termios;
+            libc::cfmakeraw(&mut termios);
+            // keep translating "\n" to "\r\n"
+            termios.c_oflag |= libc::OPOST;
+            if libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &termios) < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            print!("\x1b[?1049h\x1b[?25l");
+            io::stdout().flush()?;
+            Ok(RawTerminal { original })
+        }
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        print!("\x1b[0m\x1b[?25h\x1b[?1049l");
+        let _ = io::stdout().flush();
+        unsafe {
+            libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &self.original);
+        }
+    }
+}
+
+// waits for a key press, None if `timeout` passes first
+fn read_key(timeout: Option<Duration>) -> io::Result<Option<u8>> {
+    let mut fd = libc::pollfd {
+        fd: libc::STDIN_FILENO,
+        events: libc::POLLIN,
+        revents: 0,
+    };
+    let millis = timeout.map_or(-1, |t| t.as_millis().min(i32::MAX as u128) as i32);
+    let mut key = 0u8;
+    unsafe {
+        match libc::poll(&mut fd, 1, millis) {
+            0 => Ok(None),
+            n if n < 0 => Err(io::Error::last_os_error()),
+            _ => match libc::read(libc::STDIN_FILENO, &mut key as *mut u8 as *mut _, 1) {
+                1 => Ok(Some(key)),
+                n if n < 0 => Err(io::Error::last_os_error()),
+                // end of input quits
+                _ => Ok(Some(b'q')),
+            },
+        }
+    }
+}
+
+// columns and rows of the terminal
+fn terminal_size() -> Option<(usize, usize)> {
+    unsafe {
+        let mut size: libc::winsize = std::mem::zeroed();
+        match libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) {
+            0 if size.ws_col > 0 => Some((size.ws_col as usize, size.ws_row as usize)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::grid::Grid;
+    use crate::viz::{dump, render, visualizer, Action, Cell, Controls, Frame};
+    use crate::y2021::YEAR;
+
+    fn frame() -> Frame {
+        Frame {
+            caption: "test".to_string(),
+            cells: Grid::from_rows(vec![
+                vec![Cell::digit(0), Cell::digit(0), Cell::plain('#')],
+                vec![Cell::digit(9), Cell::heat('x', 12), Cell::plain('.')],
+            ]),
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!("00#\n9x.\n", render(&frame(), false, 10, 10));
+        assert_eq!("00\n", render(&frame(), false, 2, 1));
+        assert_eq!(
+            "\x1b[38;5;17m00\x1b[0m#\n\x1b[38;5;226m9x\x1b[0m.\n",
+            render(&frame(), true, 10, 10)
+        );
+    }
+
+    #[test]
+    fn test_controls() {
+        let mut c = Controls {
+            paused: false,
+            fps: 10.0,
+        };
+        assert_eq!(Action::Redraw, c.key(b' '));
+        assert!(c.paused);
+        assert_eq!(Action::Redraw, c.key(b' '));
+        assert!(!c.paused);
+        assert_eq!(Action::Step, c.key(b'n'));
+        assert!(c.paused);
+        c.key(b'+');
+        assert_eq!(20.0, c.fps);
+        c.key(b'-');
+        c.key(b'-');
+        assert_eq!(5.0, c.fps);
+        assert_eq!(Action::None, c.key(b'x'));
+        assert_eq!(Action::Quit, c.key(b'q'));
+        assert_eq!(Action::Quit, c.key(3));
+    }
+
+    #[test]
+    fn test_visualizers() {
+        let frames = visualizer(YEAR, 11).unwrap()("11111\n19991\n19191\n19991\n11111").unwrap();
+        let mut out = vec![];
+        dump(frames.take(3), &mut out).unwrap();
+        assert_eq!(
+            "frame 0: step 0, 0 flashes\n\
+             11111\n19991\n19191\n19991\n11111\n\n\
+             frame 1: step 1, 9 flashes\n\
+             34543\n40004\n50005\n40004\n34543\n\n\
+             frame 2: step 2, 0 flashes\n\
+             45654\n51115\n61116\n51115\n45654\n\n",
+            String::from_utf8(out).unwrap()
+        );
+
+        // the herds stop after one step
+        let frames = visualizer(YEAR, 25).unwrap()(">.v\n..v\n..v")
+            .unwrap()
+            .take(10)
+            .map(|f| f.caption)
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["step 0", "step 1"], frames);
+
+        assert_eq!(1, visualizer(YEAR, 9).unwrap()("21\n39").unwrap().count());
+        assert!(visualizer(YEAR, 1).is_err());
+    }
+}
@@ -0,0 +1,91 @@
+use crate::util::parse_lines;
+use crate::Day;
+use anyhow::Result;
+use itertools::izip;
+
+pub struct Day1;
+
+impl Day for Day1 {
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Vec<usize>> {
+        Ok(parse_lines::<usize>(input)?)
+    }
+
+    fn part1(numbers: &Vec<usize>) -> Result<String> {
+        let r = numbers
+            .iter()
+            .skip(1)
+            .zip(numbers.iter())
+            .filter(|(now, before)| now > before)
+            .count();
+
+        Ok(format!("{}", r))
+    }
+
+    fn part2(numbers: &Vec<usize>) -> Result<String> {
+        let xs: Vec<usize> = izip!(
+            numbers.iter().skip(2),
+            numbers.iter().skip(1),
+            numbers.iter()
+        )
+        .map(|(x1, x2, x3)| x1 + x2 + x3)
+        .collect();
+
+        let r = xs
+            .iter()
+            .skip(1)
+            .zip(xs.iter())
+            .filter(|(now, before)| now > before)
+            .count();
+
+        Ok(format!("{}", r))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2021::day1::Day1;
+    use crate::Day;
+    use indoc::indoc;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            Day1::solution1(indoc!(
+                "199
+                    200
+                    208
+                    210
+                    200
+                    207
+                    240
+                    269
+                    260
+                    263"
+            ))
+            .unwrap(),
+            "7"
+        )
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            Day1::solution2(indoc!(
+                "199
+                    200
+                    208
+                    210
+                    200
+                    207
+                    240
+                    269
+                    260
+                    263"
+            ))
+            .unwrap(),
+            "5"
+        )
+    }
+}
@@ -0,0 +1,172 @@
+use crate::util::parse_lines_regex;
+use crate::{debug, Day};
+use anyhow::Result;
+use itertools::Itertools;
+use std::collections::VecDeque;
+
+fn opening_char(c: char) -> Option<char> {
+    match c {
+        '}' => Some('{'),
+        ')' => Some('('),
+        ']' => Some('['),
+        '>' => Some('<'),
+        _ => None,
+    }
+}
+
+fn closing_char(c: char) -> Option<char> {
+    match c {
+        '{' => Some('}'),
+        '(' => Some(')'),
+        '[' => Some(']'),
+        '<' => Some('>'),
+        _ => None,
+    }
+}
+
+pub struct Day10;
+
+impl Day for Day10 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        let xs = parse_lines_regex(input, r"^([(){}\[\]<>]+)$")?;
+
+        Ok(xs.iter().map(|l| l[1].clone()).collect_vec())
+    }
+
+    fn part1(lines: &Vec<String>) -> Result<String> {
+        let mut stack: VecDeque<char> = Default::default();
+
+        let result: i32 = lines
+            .iter()
+            .filter_map(|line| {
+                let mut error = None;
+
+                for c in line.chars() {
+                    match opening_char(c) {
+                        Some(open_char) => match stack.pop_back() {
+                            None => panic!("unexpected end"),
+                            Some(actual) => {
+                                if actual != open_char {
+                                    debug!("Expected {} got {} instead", open_char, actual);
+                                    error = Some(match c {
+                                        ')' => 3,
+                                        ']' => 57,
+                                        '}' => 1197,
+                                        '>' => 25137,
+                                        _ => panic!("unexpected error char"),
+                                    });
+                                    return error;
+                                }
+                            }
+                        },
+                        _ => stack.push_back(c),
+                    };
+                }
+
+                error
+            })
+            .sum();
+
+        Ok(format!("{}", result))
+    }
+
+    fn part2(lines: &Vec<String>) -> Result<String> {
+        let mut result = lines
+            .iter()
+            .filter_map(|line| {
+                let mut stack: VecDeque<char> = Default::default();
+
+                for c in line.trim().chars() {
+                    match opening_char(c) {
+                        Some(open_char) => match stack.pop_back() {
+                            None => panic!("unexpected end"),
+                            Some(actual) => {
+                                if actual != open_char {
+                                    debug!("Expected {} got {} instead", open_char, actual);
+                                    return None;
+                                }
+                            }
+                        },
+                        _ => stack.push_back(c),
+                    };
+                }
+
+                debug!("left is: {}", stack.iter().join(""));
+
+                let mut score: u64 = 0;
+                loop {
+                    match stack.pop_back() {
+                        None => break,
+                        Some(c) => {
+                            let closing_char = closing_char(c).unwrap();
+
+                            score *= 5;
+                            score += match closing_char {
+                                ')' => 1,
+                                ']' => 2,
+                                '}' => 3,
+                                '>' => 4,
+                                _ => panic!("unexpected error char"),
+                            }
+                        }
+                    }
+                }
+
+                Some(score)
+            })
+            .collect_vec();
+
+        result.sort();
+        Ok(format!("{}", result[result.len() / 2]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::run_solution;
+    use crate::y2021::day10::Day10;
+    use crate::Day;
+    use indoc::indoc;
+
+    const INPUT: &str = "2021/day10.txt";
+
+    // PART 1
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            "1197",
+            Day10::solution1(indoc!("{([(<{}[<>[]}>{[]{[(<()>")).unwrap()
+        );
+    }
+
+    #[test]
+    fn run_solution1() {
+        run_solution(INPUT, Day10::solution1).unwrap()
+    }
+
+    // PART 2
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            "288957",
+            Day10::solution2(indoc!(
+                "
+    [({(<(())[]>[[{[]{<()<>>
+    [(()[<>])]({[<{<<[]>>(
+    (((({<>}<{<{<>}{[]{[]{}
+    {<[[]]>}<{[{[{[]{()[[[]
+    <{([{{}}[<[[[<>{}]]]>[]]"
+            ))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn run_solution2() {
+        run_solution(INPUT, Day10::solution2).unwrap()
+    }
+}
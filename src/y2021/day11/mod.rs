@@ -0,0 +1,174 @@
+use crate::util::grid::Grid;
+use crate::util::parse_digit_grid;
+use crate::util::sim::{Sim, Simulation};
+use crate::viz::{Cell, Frame, Frames};
+use crate::Day;
+use anyhow::{bail, Result};
+
+// advances the octopuses by one step, returns the number of flashes
+fn step(ys: &mut Grid<i32>) -> usize {
+    ys.positions().for_each(|p| ys[p] += 1);
+
+    let mut flashes = 0;
+    let mut was_flash = true;
+
+    while was_flash {
+        was_flash = false;
+        for p in ys.positions() {
+            if ys[p] > 9 {
+                ys[p] = -1;
+                was_flash = true;
+                flashes += 1;
+
+                for n in ys.adjacent(p) {
+                    if ys[n] >= 0 {
+                        ys[n] += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    for p in ys.positions() {
+        if ys[p] == -1 {
+            ys[p] = 0;
+        }
+    }
+    flashes
+}
+
+#[derive(Clone, Hash, PartialEq, Eq)]
+struct Octopuses(Grid<i32>);
+
+impl Simulation for Octopuses {
+    fn step(&mut self) {
+        step(&mut self.0);
+    }
+}
+
+// the energy levels after every step, octopuses that just flashed are the hottest
+pub fn frames(input: &str) -> Result<Frames> {
+    let mut sim = Sim::new(Octopuses(Day11::parse(input)?));
+    let mut first = true;
+    Ok(Box::new(std::iter::from_fn(move || {
+        if !std::mem::take(&mut first) {
+            sim.step();
+        }
+        let grid = &sim.state().0;
+        let flashes = grid.values().filter(|&&x| x == 0).count();
+        Some(Frame {
+            caption: format!("step {}, {} flashes", sim.steps(), flashes),
+            cells: grid.map(|&x| match x {
+                0 if sim.steps() > 0 => Cell::heat('0', 9),
+                _ => Cell::digit(x as u8),
+            }),
+        })
+    })))
+}
+
+// grids that take longer are taken to never flash together
+const MAX_STEPS: usize = 100_000;
+
+pub struct Day11;
+
+impl Day for Day11 {
+    type Input = Grid<i32>;
+
+    fn parse(input: &str) -> Result<Grid<i32>> {
+        Ok(parse_digit_grid(input)?)
+    }
+
+    fn part1(grid: &Grid<i32>) -> Result<String> {
+        let mut ys = grid.clone();
+
+        let flashes: usize = (0..100).map(|_| step(&mut ys)).sum();
+
+        Ok(format!("{}", flashes))
+    }
+
+    fn part2(grid: &Grid<i32>) -> Result<String> {
+        let mut sim = Sim::new(Octopuses(grid.clone()));
+        match sim.run_until_after_step(MAX_STEPS, |x| x.0.values().all(|&x| x == 0)) {
+            Some(step_no) => Ok(format!("{}", step_no)),
+            None => bail!(
+                "The octopuses don't flash together within {} steps",
+                MAX_STEPS
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::run_solution;
+    use crate::y2021::day11::Day11;
+    use crate::Day;
+    use indoc::indoc;
+
+    const INPUT: &str = "2021/day11.txt";
+
+    // PART 1
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            "1656",
+            Day11::solution1(indoc!(
+                "5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526"
+            ))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn run_solution1() {
+        run_solution(INPUT, Day11::solution1).unwrap()
+    }
+
+    // PART 2
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            "195",
+            Day11::solution2(indoc!(
+                "5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526"
+            ))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_part2_synchronized() {
+        // a grid of zeros has not flashed yet, it flashes together after ten steps
+        assert_eq!("10", Day11::solution2("000\n000\n000").unwrap());
+    }
+
+    #[test]
+    fn test_part2_never_synchronized() {
+        assert!(Day11::solution2("02").is_err());
+    }
+
+    #[test]
+    fn run_solution2() {
+        run_solution(INPUT, Day11::solution2).unwrap()
+    }
+}
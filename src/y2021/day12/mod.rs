@@ -0,0 +1,141 @@
+use crate::util::parse_regex;
+use crate::util::search::{count_paths, Successors};
+use crate::{debug, Day};
+use anyhow::Result;
+use std::collections::{BTreeSet, HashMap};
+
+struct Caves<'a> {
+    edges: HashMap<&'a str, Vec<&'a str>>,
+    // a single small cave may be visited twice
+    revisit: bool,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct Walk<'a> {
+    cave: &'a str,
+    visited: BTreeSet<&'a str>,
+    revisited: bool,
+}
+
+fn is_small(cave: &str) -> bool {
+    cave.chars().next().unwrap().is_lowercase()
+}
+
+impl<'a> Successors for Caves<'a> {
+    type Node = Walk<'a>;
+
+    fn successors(&self, walk: &Walk<'a>) -> Vec<(Walk<'a>, u64)> {
+        self.edges[walk.cave]
+            .iter()
+            .filter(|&&to| to != "start")
+            .filter_map(|&to| {
+                let mut next = walk.clone();
+                next.cave = to;
+                if is_small(to) && !next.visited.insert(to) {
+                    if !self.revisit || next.revisited {
+                        return None;
+                    }
+                    next.revisited = true;
+                }
+                Some((next, 1))
+            })
+            .collect()
+    }
+}
+
+fn paths(edges: &[(String, String)], revisit: bool) -> u64 {
+    let mut caves = Caves {
+        edges: HashMap::new(),
+        revisit,
+    };
+    for (a, b) in edges {
+        caves.edges.entry(a.as_str()).or_default().push(b.as_str());
+        caves.edges.entry(b.as_str()).or_default().push(a.as_str());
+    }
+
+    let start = Walk {
+        cave: "start",
+        visited: BTreeSet::from(["start"]),
+        revisited: false,
+    };
+    // the big caves are never connected to each other, so there are no cycles
+    let (n, stats) = count_paths(&caves, start, |w| w.cave == "end");
+    debug!("{:?}", stats);
+    n
+}
+
+pub struct Day12;
+
+impl Day for Day12 {
+    type Input = Vec<(String, String)>;
+
+    fn parse(input: &str) -> Result<Vec<(String, String)>> {
+        Ok(parse_regex(input, r"^(\w+)-(\w+)$")?)
+    }
+
+    fn part1(edges: &Vec<(String, String)>) -> Result<String> {
+        Ok(format!("{}", paths(edges, false)))
+    }
+
+    fn part2(edges: &Vec<(String, String)>) -> Result<String> {
+        Ok(format!("{}", paths(edges, true)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::run_solution;
+    use crate::y2021::day12::Day12;
+    use crate::Day;
+    use indoc::indoc;
+
+    const INPUT: &str = "2021/day12.txt";
+
+    // PART 1
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            "10",
+            Day12::solution1(indoc!(
+                "start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end"
+            ))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn run_solution1() {
+        run_solution(INPUT, Day12::solution1).unwrap()
+    }
+
+    // PART 2
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            "36",
+            Day12::solution2(indoc!(
+                "start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end"
+            ))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn run_solution2() {
+        run_solution(INPUT, Day12::solution2).unwrap()
+    }
+}
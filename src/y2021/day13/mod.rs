@@ -0,0 +1,211 @@
+use crate::util::{parse_regex, sections};
+use crate::Day;
+use anyhow::Result;
+use itertools::Itertools;
+use std::collections::HashSet;
+
+fn fold_x(along_x: usize, marks: &mut HashSet<(usize, usize)>, max_x: usize, max_y: usize) {
+    (0..=max_y).for_each(|y| {
+        marks.remove(&(along_x, y));
+    });
+
+    (along_x + 1..=max_x).for_each(|x| {
+        (0..=max_y).for_each(|y| {
+            let fold_to = along_x as i32 - (x as i32 - along_x as i32);
+            if fold_to >= 0 && marks.remove(&(x, y)) {
+                marks.insert((fold_to as usize, y));
+            }
+        })
+    })
+}
+
+fn fold_y(along_y: usize, marks: &mut HashSet<(usize, usize)>, max_x: usize, max_y: usize) {
+    (0..=max_x).for_each(|x| {
+        marks.remove(&(x, along_y));
+    });
+
+    (0..=max_x).for_each(|x| {
+        (along_y + 1..=max_y).for_each(|y| {
+            let fold_to = along_y as i32 - (y as i32 - along_y as i32);
+            if fold_to >= 0 && marks.remove(&(x, y)) {
+                marks.insert((x, fold_to as usize));
+            }
+        })
+    })
+}
+
+// the paper up to the last dot in each direction, `#` for a dot
+fn render(marks: &HashSet<(usize, usize)>) -> String {
+    let max_x = marks.iter().map(|&(x, _)| x).max().unwrap_or(0);
+    let max_y = marks.iter().map(|&(_, y)| y).max().unwrap_or(0);
+    (0..=max_y)
+        .map(|y| {
+            (0..=max_x)
+                .map(|x| if marks.contains(&(x, y)) { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .join("\n")
+}
+
+pub struct Manual {
+    marks: HashSet<(usize, usize)>,
+    max_x: usize,
+    max_y: usize,
+    folds: Vec<(String, usize)>,
+}
+
+pub struct Day13;
+
+impl Day for Day13 {
+    type Input = Manual;
+
+    fn parse(input: &str) -> Result<Manual> {
+        let sections = sections(input);
+        let dots: Vec<(usize, usize)> = match sections.first() {
+            Some(s) => s.parse(|s| parse_regex(s, "^(.+?),(.+?)$"))?,
+            None => vec![],
+        };
+        let folds = match sections.get(1) {
+            Some(s) => s.parse(|s| parse_regex(s, "^fold along (x|y)=([0-9]+)$"))?,
+            None => vec![],
+        };
+
+        let marks: HashSet<(usize, usize)> = dots.iter().copied().collect();
+        let max_x = dots.iter().map(|&(x, _)| x).max().unwrap_or(0);
+        let max_y = dots.iter().map(|&(_, y)| y).max().unwrap_or(0);
+
+        Ok(Manual {
+            marks,
+            max_x,
+            max_y,
+            folds,
+        })
+    }
+
+    fn part1(manual: &Manual) -> Result<String> {
+        let mut marks = manual.marks.clone();
+        let (max_x, max_y) = (manual.max_x, manual.max_y);
+
+        for (dir, loc) in manual.folds.iter().take(1) {
+            match dir.as_str() {
+                "x" => fold_x(*loc, &mut marks, max_x, max_y),
+                "y" => fold_y(*loc, &mut marks, max_x, max_y),
+                _ => {}
+            }
+        }
+
+        Ok(format!("{}", marks.len()))
+    }
+
+    fn part2(manual: &Manual) -> Result<String> {
+        let mut marks = manual.marks.clone();
+        let (max_x, max_y) = (manual.max_x, manual.max_y);
+
+        for (dir, loc) in manual.folds.iter() {
+            match dir.as_str() {
+                "x" => fold_x(*loc, &mut marks, max_x, max_y),
+                "y" => fold_y(*loc, &mut marks, max_x, max_y),
+                _ => {}
+            }
+        }
+
+        // the code is written in letters of dots
+        Ok(render(&marks))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::run_solution;
+    use crate::util::ParseError;
+    use crate::y2021::day13::Day13;
+    use crate::Day;
+    use indoc::indoc;
+
+    const INPUT: &str = "2021/day13.txt";
+
+    // PART 1
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            "17",
+            Day13::solution1(indoc!(
+                "6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5"
+            ))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = Day13::parse("6,10\n0,14\n\nfold along y=7\nfold along z=5")
+            .err()
+            .unwrap();
+        let e = e.downcast::<ParseError>().unwrap();
+        assert_eq!((5, "fold along z=5"), (e.line, e.source.as_str()));
+    }
+
+    #[test]
+    fn run_solution1() {
+        run_solution(INPUT, Day13::solution1).unwrap()
+    }
+
+    // PART 2
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            "#####\n#...#\n#...#\n#...#\n#####",
+            Day13::solution2(indoc!(
+                "6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5"
+            ))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn run_solution2() {
+        run_solution(INPUT, Day13::solution2).unwrap()
+    }
+}
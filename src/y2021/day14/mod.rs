@@ -0,0 +1,285 @@
+use crate::util::memo::Memo;
+use crate::util::parse_regex;
+use crate::{debug, Day};
+use anyhow::Result;
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+fn from_pair(p: (char, char), map: &str) -> Vec<u64> {
+    let mut v = vec![0; map.len()];
+    v[map.find(p.0).unwrap()] = 1;
+    v
+}
+
+fn cnt_rec(
+    map: &str,
+    memo: &mut Memo<(char, char, usize), Vec<u64>>,
+    step: usize,
+    max_step: usize,
+    pair: (char, char),
+    rules: &HashMap<(char, char), char>,
+) -> Vec<u64> {
+    if step == max_step {
+        return from_pair(pair, map);
+    }
+
+    memo.get((pair.0, pair.1, step), |memo| match rules.get(&pair) {
+        // pairs without a rule stay as they are
+        None => from_pair(pair, map),
+        Some(s) => cnt_rec(map, memo, step + 1, max_step, (pair.0, *s), rules)
+            .iter()
+            .zip(cnt_rec(map, memo, step + 1, max_step, (*s, pair.1), rules))
+            .map(|(x1, x2)| x1 + x2)
+            .collect_vec(),
+    })
+}
+
+pub type Rule = ((char, char), char);
+
+// inserts the elements one by one, the polymer doubles in length with every step
+pub fn expand(template: &[char], rules: &[Rule], steps: usize) -> Vec<char> {
+    let mut x = template.to_vec();
+
+    for _step in 0..steps {
+        let mut i = 0;
+        loop {
+            if i + 1 >= x.len() {
+                break;
+            }
+
+            let c1 = x[i];
+            let c2 = x[i + 1];
+            for ins in rules.iter() {
+                if ins.0 .0 == c1 && ins.0 .1 == c2 {
+                    i += 1;
+                    //println!("Insert {} at pos {}", ins.1, i);
+                    x.insert(i, ins.1);
+                }
+            }
+            //NBCCNBBBCBHCB
+            //NBCCNBBBC HCB
+
+            i += 1;
+        }
+
+        debug!("step {} len {}", _step, x.len());
+        // println!("{}", x.iter().join(""));
+    }
+
+    x
+}
+
+// the count of every element after `steps` steps, in the order of the returned elements
+pub fn count_elements(template: &[char], rules: &[Rule], steps: usize) -> (String, Vec<u64>) {
+    let x = template;
+    let mut all_chars: HashSet<char> = x.iter().copied().collect();
+    let mut map: HashMap<(char, char), char> = HashMap::new();
+    for ((c1, c2), c) in rules {
+        all_chars.insert(*c1);
+        all_chars.insert(*c2);
+        all_chars.insert(*c);
+        map.insert((*c1, *c2), *c);
+    }
+
+    let char_map = all_chars.iter().sorted().join("");
+
+    // every pair counts its first element, so the last one is added separately
+    let mut last = vec![0; char_map.len()];
+    if let Some(c) = x.last() {
+        last[char_map.find(*c).unwrap()] = 1;
+    }
+
+    let mut memo = Memo::new();
+    let res = (0..x.len().saturating_sub(1))
+        .map(|i| cnt_rec(&char_map, &mut memo, 0, steps, (x[i], x[i + 1]), &map))
+        .fold(last, |mut x, s| {
+            for i in 0..x.len() {
+                x[i] += s[i];
+            }
+            x
+        });
+    debug!("pair cache: {}", memo.stats());
+
+    (char_map, res)
+}
+
+pub struct Day14;
+
+impl Day for Day14 {
+    type Input = (Vec<char>, Vec<((char, char), char)>);
+
+    fn parse(input: &str) -> Result<(Vec<char>, Vec<((char, char), char)>)> {
+        let mut lines = input.lines().enumerate();
+        let template = lines.next().map_or("", |(_, l)| l).chars().collect_vec();
+        // the rules follow the template, with or without a blank line in between
+        let rules: Vec<(char, char, char)> = lines
+            .filter(|(_, l)| !l.trim().is_empty())
+            .map(|(i, l)| {
+                parse_regex(l, r"^(\w)(\w) -> (\w)$")
+                    .map(|x| x[0])
+                    .map_err(|e| e.offset(i))
+            })
+            .try_collect()?;
+        let rules = rules.into_iter().map(|(a, b, c)| ((a, b), c)).collect_vec();
+
+        Ok((template, rules))
+    }
+
+    fn part1((template, rules): &(Vec<char>, Vec<((char, char), char)>)) -> Result<String> {
+        let counts = expand(template, rules, 10).into_iter().counts();
+        let most = counts.values().max().unwrap();
+        let least = counts.values().min().unwrap();
+
+        Ok(format!("{}", most - least))
+    }
+
+    fn part2((template, rules): &(Vec<char>, Vec<((char, char), char)>)) -> Result<String> {
+        let (_, res) = count_elements(template, rules, 40);
+        // elements that only appear in rules that never apply are not in the polymer
+        let counts = res.into_iter().filter(|&x| x > 0).collect_vec();
+        let most = counts.iter().max().unwrap();
+        let least = counts.iter().min().unwrap();
+
+        Ok(format!("{}", most - least))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gen::check::Checker;
+    use crate::gen::polymer;
+    use crate::run_solution;
+    use crate::util::ParseError;
+    use crate::y2021::day14::{count_elements, expand, Day14};
+    use crate::Day;
+    use indoc::indoc;
+    use itertools::Itertools;
+    use std::collections::BTreeMap;
+
+    const INPUT: &str = "2021/day14.txt";
+
+    // PART 1
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            "1588",
+            Day14::solution1(indoc!(
+                "NNCB
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C"
+            ))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        // the puzzle input has no blank line after the template
+        let (template, rules) = Day14::parse("KBKP\nOP -> H\nCF -> C\n").unwrap();
+        assert_eq!(vec!['K', 'B', 'K', 'P'], template);
+        assert_eq!(vec![(('O', 'P'), 'H'), (('C', 'F'), 'C')], rules);
+
+        assert_eq!(
+            (template, rules),
+            Day14::parse("KBKP\n\nOP -> H\nCF -> C").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        // errors point at the line of the whole input, blank lines included
+        for (input, line) in [("NNCB\nCH -> B\nHH => N", 3), ("NNCB\n\nCH = B", 3)] {
+            let e = Day14::parse(input).err().unwrap();
+            let e = e.downcast::<ParseError>().unwrap();
+            assert_eq!(line, e.line);
+        }
+        let e = Day14::parse("NNCB\nC -> B").err().unwrap();
+        assert_eq!(2, e.downcast::<ParseError>().unwrap().line);
+    }
+
+    #[test]
+    fn run_solution1() {
+        run_solution(INPUT, Day14::solution1).unwrap()
+    }
+
+    // PART 2
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            "2188189693529",
+            Day14::solution2(indoc!(
+                "NNCB
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C"
+            ))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn run_solution2() {
+        run_solution(INPUT, Day14::solution2).unwrap()
+    }
+
+    #[test]
+    fn test_count_elements() {
+        // NNCB -> NCNCB, the pairs NC and CB have no rule
+        let (elements, counts) = count_elements(&['N', 'N', 'C', 'B'], &[(('N', 'N'), 'C')], 1);
+        assert_eq!(("BCN", vec![1, 2, 2]), (elements.as_str(), counts));
+    }
+
+    #[test]
+    fn test_differential() {
+        Checker::default().assert_agree::<Day14, _>(
+            polymer,
+            6,
+            7,
+            |(template, rules), steps| {
+                let (elements, counts) = count_elements(template, rules, steps);
+                elements
+                    .chars()
+                    .zip(counts)
+                    .filter(|(_, n)| *n > 0)
+                    .collect::<BTreeMap<_, _>>()
+            },
+            |(template, rules), steps| {
+                expand(template, rules, steps)
+                    .into_iter()
+                    .counts()
+                    .into_iter()
+                    .map(|(c, n)| (c, n as u64))
+                    .collect()
+            },
+        );
+    }
+}
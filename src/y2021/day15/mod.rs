@@ -0,0 +1,139 @@
+use crate::util::grid::{Grid, Pos};
+use crate::util::search::{astar, Successors};
+use crate::{debug, Day};
+use anyhow::{anyhow, Result};
+
+struct Cave<'a> {
+    risk: &'a Grid<i32>,
+    goal: Pos,
+}
+
+impl Successors for Cave<'_> {
+    type Node = Pos;
+
+    fn successors(&self, &p: &Pos) -> Vec<(Pos, u64)> {
+        self.risk
+            .orthogonal(p)
+            .map(|n| (n, self.risk[n] as u64))
+            .collect()
+    }
+
+    // every step costs at least 1, which parse makes sure of
+    fn heuristic(&self, &(x, y): &Pos) -> u64 {
+        (self.goal.0.abs_diff(x) + self.goal.1.abs_diff(y)) as u64
+    }
+}
+
+// None for an empty cave
+fn lowest_risk(risk: &Grid<i32>) -> Option<u64> {
+    let goal = (risk.width().checked_sub(1)?, risk.height().checked_sub(1)?);
+    let search = astar(&Cave { risk, goal }, (0, 0), |&p| p == goal);
+    debug!("{:?}", search.stats);
+    search.cost()
+}
+
+pub struct Day15;
+
+impl Day for Day15 {
+    type Input = Grid<i32>;
+
+    fn parse(input: &str) -> Result<Grid<i32>> {
+        Ok(Grid::parse(input, "a risk from 1 to 9", |c| {
+            c.to_digit(10).filter(|&d| d >= 1).map(|d| d as i32)
+        })?)
+    }
+
+    fn part1(ys: &Grid<i32>) -> Result<String> {
+        let risk = lowest_risk(ys).ok_or_else(|| anyhow!("The cave is empty"))?;
+        Ok(format!("{}", risk))
+    }
+
+    fn part2(ys: &Grid<i32>) -> Result<String> {
+        let x_len = ys.width();
+        let y_len = ys.height();
+
+        let ys = Grid::from_fn(x_len * 5, y_len * 5, |(x, y)| {
+            let mut w = ys[(x % x_len, y % y_len)];
+            w = w + (x / x_len) as i32 + (y / y_len) as i32;
+            if w > 9 {
+                w -= 9;
+            }
+            w
+        });
+
+        let risk = lowest_risk(&ys).ok_or_else(|| anyhow!("The cave is empty"))?;
+        Ok(format!("{}", risk))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::run_solution;
+    use crate::util::ParseError;
+    use crate::y2021::day15::Day15;
+    use crate::Day;
+    use indoc::indoc;
+    const INPUT: &str = "2021/day15.txt";
+
+    // PART 1
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            "40",
+            Day15::solution1(indoc!(
+                "1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581"
+            ))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = Day15::parse("119\n109").err().unwrap();
+        let e = e.downcast::<ParseError>().unwrap();
+        assert_eq!((2, 1..2), (e.line, e.columns));
+        assert!(Day15::solution1("").is_err());
+    }
+
+    #[test]
+    fn run_solution1() {
+        run_solution(INPUT, Day15::solution1).unwrap()
+    }
+
+    // PART 2
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            "315",
+            Day15::solution2(indoc!(
+                "1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581"
+            ))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn run_solution2() {
+        run_solution(INPUT, Day15::solution2).unwrap()
+    }
+}
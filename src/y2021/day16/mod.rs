@@ -0,0 +1,234 @@
+use crate::util::ParseError;
+use crate::y2021::day16::Package::{Lit, Op};
+use crate::{trace, Day};
+use anyhow::Result;
+use itertools::Itertools;
+use std::collections::VecDeque;
+
+pub enum Package {
+    Lit(u32, u64),
+    Op(u32, u32, Vec<Package>),
+}
+
+fn take_bits(deque: &mut VecDeque<char>, n: usize) -> Result<u32, &'static str> {
+    let x = take_binary_str(deque, n)?;
+    //println!("Took {} bits got {}", n, x);
+
+    u32::from_str_radix(&x, 2).map_err(|_| "a smaller number")
+}
+
+fn take_binary_str(deque: &mut VecDeque<char>, n: usize) -> Result<String, &'static str> {
+    let mut x = String::new();
+    for _ in 0..n {
+        x += &String::from(deque.pop_front().ok_or("more bits")?);
+    }
+    //println!("Took {} bits got {}", n, x);
+    Ok(x)
+}
+
+fn parse_lit_package(v: u32, s: &mut VecDeque<char>) -> Result<Package, &'static str> {
+    let mut x = String::new();
+    loop {
+        let cont = take_bits(s, 1)? == 1;
+        x += &take_binary_str(s, 4)?;
+        if !cont {
+            break;
+        }
+    }
+    let i = u64::from_str_radix(&x, 2).map_err(|_| "a literal fitting into 64 bits")?;
+
+    trace!("Lit package: got {}", i);
+    Ok(Lit(v, i))
+}
+
+fn parse_op_package(v: u32, typ: u32, s: &mut VecDeque<char>) -> Result<Package, &'static str> {
+    let l = take_bits(s, 1)?;
+
+    let vec = match l {
+        0 => {
+            let bits = take_bits(s, 15)? as usize;
+            trace!("Operator Package: bits = {}", bits);
+            let mut r = Vec::new();
+
+            let len_start = s.len();
+            loop {
+                r.push(parse_package(s)?);
+                trace!(
+                    "   Parsed subpacket consumed already {} bits, must consume {} bits",
+                    len_start - s.len(),
+                    bits
+                );
+                if len_start - s.len() == bits {
+                    break;
+                }
+                if len_start - s.len() > bits {
+                    return Err("subpackets matching the declared length");
+                }
+            }
+            r
+        }
+        _ => {
+            let len = take_bits(s, 11)?;
+            trace!("Operator Package: len = {}", len);
+            (0..len).map(|_s| parse_package(s)).try_collect()?
+        }
+    };
+
+    Ok(Op(v, typ, vec))
+}
+
+fn parse_package(s: &mut VecDeque<char>) -> Result<Package, &'static str> {
+    let version = take_bits(s, 3)?;
+    let typ = take_bits(s, 3)?;
+    trace!("Parsing pkg v={}, type={}", version, typ);
+
+    match typ {
+        4 => parse_lit_package(version, s),
+        _ => parse_op_package(version, typ, s),
+    }
+}
+
+fn version_sum(p: &Package) -> u64 {
+    match p {
+        Lit(v, _) => *v as u64,
+        Op(v, _, p) => *v as u64 + p.iter().map(version_sum).sum::<u64>(),
+    }
+}
+
+// the value of a package
+fn extract_res(p: &Package) -> u64 {
+    match p {
+        Lit(_v, val) => *val,
+        Op(_v, typ, p) => match typ {
+            0 => p.iter().map(extract_res).sum::<u64>(),
+            1 => p.iter().map(extract_res).product::<u64>(),
+            2 => p.iter().map(extract_res).min().unwrap(),
+            3 => p.iter().map(extract_res).max().unwrap(),
+            5 => {
+                let vv = p.iter().map(extract_res).collect_vec();
+                if vv[0] > vv[1] {
+                    1
+                } else {
+                    0
+                }
+            }
+            6 => {
+                let vv = p.iter().map(extract_res).collect_vec();
+                if vv[0] < vv[1] {
+                    1
+                } else {
+                    0
+                }
+            }
+            7 => {
+                let vv = p.iter().map(extract_res).collect_vec();
+                if vv[0] == vv[1] {
+                    1
+                } else {
+                    0
+                }
+            }
+            _ => panic!("unexpected typ"),
+        },
+    }
+}
+
+pub struct Day16;
+
+impl Day for Day16 {
+    type Input = Vec<Package>;
+
+    fn parse(input: &str) -> Result<Vec<Package>> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let zero_vec = line
+                    .char_indices()
+                    .map(|(j, x)| match x.to_digit(16) {
+                        Some(d) => Ok(format!("{:04b}", d)),
+                        None => Err(ParseError::new(i + 1, line, j..j + 1, "a hex digit")),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join("");
+                let mut deque = VecDeque::new();
+                for c in zero_vec.chars() {
+                    deque.push_back(c);
+                }
+
+                trace!("{}", zero_vec);
+
+                parse_package(&mut deque).map_err(|expected| {
+                    let column = ((zero_vec.len() - deque.len()) / 4).min(line.len());
+                    ParseError::new(i + 1, line, column..column + 1, expected).into()
+                })
+            })
+            .collect()
+    }
+
+    fn part1(packages: &Vec<Package>) -> Result<String> {
+        let sum: u64 = packages.iter().map(version_sum).sum();
+        Ok(format!("{}", sum))
+    }
+
+    fn part2(packages: &Vec<Package>) -> Result<String> {
+        let mut sum = 0;
+        for package in packages {
+            sum += extract_res(package);
+        }
+        Ok(format!("{}", sum))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::run_solution;
+    use crate::y2021::day16::Day16;
+    use crate::Day;
+    use indoc::indoc;
+    const INPUT: &str = "2021/day16.txt";
+
+    // PART 1
+
+    #[test]
+    fn test_rr() {
+        assert_eq!("14", Day16::solution1(indoc!("EE00D40C823060")).unwrap());
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            "28",
+            Day16::solution1(indoc!(
+                "8A004A801A8002F478
+                   620080001611562C8802118E34"
+            ))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn run_solution1() {
+        run_solution(INPUT, Day16::solution1).unwrap()
+    }
+
+    // PART 2
+
+    #[test]
+    fn test_lit() {
+        assert_eq!(
+            "1",
+            Day16::solution2(indoc!("9C0141080250320F1802104A08")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!("3", Day16::solution2(indoc!("C200B40A82")).unwrap());
+    }
+
+    #[test]
+    fn run_solution2() {
+        run_solution(INPUT, Day16::solution2).unwrap()
+    }
+}
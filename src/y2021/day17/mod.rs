@@ -0,0 +1,128 @@
+use crate::util::geom::{Rect, Vec2};
+use crate::util::{parse_regex, ParseError};
+use crate::{trace, Day};
+use anyhow::Result;
+
+struct State {
+    pos: Vec2<i32>,
+    vel: Vec2<i32>,
+    target_area: Rect<i32>,
+}
+
+impl State {
+    pub fn step(&mut self) {
+        self.pos += self.vel;
+
+        if self.vel.0.abs() <= 1 {
+            self.vel.0 = 0;
+        } else {
+            self.vel.0 = self.vel.0.signum() * (self.vel.0.abs() - 1)
+        }
+        self.vel.1 -= 1;
+    }
+
+    pub fn outside_of_target_zone(&self) -> bool {
+        self.pos.0 > self.target_area.last().0 || self.pos.1 < self.target_area.min.1
+    }
+
+    pub fn finished(&self) -> bool {
+        self.target_area.contains(self.pos)
+    }
+}
+
+// the initial velocities that end up in the target area, with the highest y of each probe
+fn hits(target_area: &Rect<i32>) -> Vec<((i32, i32), i32)> {
+    let mut successes = Vec::new();
+
+    for x in -1000..1000 {
+        for y in -1000..1000 {
+            let mut s = State {
+                pos: Vec2(0, 0),
+                vel: Vec2(x, y),
+                target_area: *target_area,
+            };
+
+            let mut highest_y = i32::MIN;
+
+            loop {
+                s.step();
+                highest_y = highest_y.max(s.pos.1);
+
+                if s.finished() {
+                    successes.push(((x, y), highest_y));
+                    trace!("{:?}: highy = {}", (x, y), highest_y);
+                    break;
+                }
+                if s.outside_of_target_zone() {
+                    break;
+                }
+            }
+        }
+    }
+
+    successes
+}
+
+pub struct Day17;
+
+impl Day for Day17 {
+    type Input = Rect<i32>;
+
+    fn parse(input: &str) -> Result<Rect<i32>> {
+        let xs = parse_regex::<(Rect<i32>,)>(input, r"^target area: (.+)$")?;
+        let &(target_area,) = xs
+            .first()
+            .ok_or_else(|| ParseError::new(1, "", 0..0, "a target area"))?;
+
+        Ok(target_area)
+    }
+
+    fn part1(target_area: &Rect<i32>) -> Result<String> {
+        let highest_y_glob = hits(target_area).iter().map(|&(_, y)| y).max();
+
+        Ok(format!("{}", highest_y_glob.unwrap_or(i32::MIN)))
+    }
+
+    fn part2(target_area: &Rect<i32>) -> Result<String> {
+        Ok(format!("{}", hits(target_area).len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::run_solution;
+    use crate::y2021::day17::Day17;
+    use crate::Day;
+    use indoc::indoc;
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            "45",
+            Day17::solution1(indoc!("target area: x=20..30, y=-10..-5")).unwrap()
+        );
+    }
+
+    const INPUT: &str = "2021/day17.txt";
+
+    // PART 1
+
+    #[test]
+    fn run_solution1() {
+        run_solution(INPUT, Day17::solution1).unwrap()
+    }
+
+    // PART 2
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            "112",
+            Day17::solution2(indoc!("target area: x=20..30, y=-10..-5")).unwrap()
+        );
+    }
+
+    #[test]
+    fn run_solution2() {
+        run_solution(INPUT, Day17::solution2).unwrap()
+    }
+}
@@ -1,12 +1,12 @@
-use crate::day18::Node::Num;
-use crate::util::{parse_lines, parse_lines_regex};
-use anyhow::Result;
-use itertools::{izip, Itertools};
-use std::collections::{HashMap, HashSet};
-use std::fmt::{write, Display, Formatter};
+use crate::util::ParseError;
+use crate::y2021::day18::Node::Num;
+use crate::Day;
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use std::fmt::{Display, Formatter};
 
 #[derive(Clone)]
-enum Node {
+pub enum Node {
     Num(u32),
     Leaf(Box<Tree>),
 }
@@ -41,27 +41,28 @@ impl Node {
         match self {
             Node::Num(c) => {
                 *c += num;
-                return true;
+                true
             }
             Node::Leaf(c) => {
-                if left {
-                    c.l.incr(left, num) || c.r.incr(left, num)
+                let (first, second) = if left {
+                    (&mut c.l, &mut c.r)
                 } else {
-                    c.r.incr(left, num) || c.l.incr(left, num)
-                }
+                    (&mut c.r, &mut c.l)
+                };
+                first.incr(left, num) || second.incr(left, num)
             }
         }
     }
 
     fn split(&mut self) -> bool {
         match self {
-            Num(x) => false,
+            Num(_) => false,
             Node::Leaf(s) => {
                 if let Node::Num(l) = s.l {
                     if l >= 10 {
                         s.l = Node::Leaf(Box::new(Tree {
                             l: Node::Num(l / 2),
-                            r: Node::Num((l + 1) / 2),
+                            r: Node::Num(l.div_ceil(2)),
                         }));
                         return true;
                     }
@@ -74,7 +75,7 @@ impl Node {
                     if r >= 10 {
                         s.r = Node::Leaf(Box::new(Tree {
                             l: Node::Num(r / 2),
-                            r: Node::Num((r + 1) / 2),
+                            r: Node::Num(r.div_ceil(2)),
                         }));
                         return true;
                     }
@@ -134,12 +135,12 @@ impl Node {
     }
 
     fn add_all(nodes: Vec<Node>) -> Node {
-        nodes.into_iter().reduce(|s, x| Node::add(s, x)).unwrap()
+        nodes.into_iter().reduce(Node::add).unwrap()
     }
 }
 
 #[derive(Clone)]
-struct Tree {
+pub struct Tree {
     l: Node,
     r: Node,
 }
@@ -156,66 +157,101 @@ impl Display for Tree {
 }
 
 impl Tree {
-    fn parse_tree(s: &str) -> (&str, Tree) {
-        assert_eq!('[', s.chars().next().unwrap());
-        let (s, l) = Self::parse_node(s.split_at(1).1);
-        assert_eq!(',', s.chars().next().unwrap());
-        let (s, r) = Self::parse_node(s.split_at(1).1);
-        assert_eq!(']', s.chars().next().unwrap());
-        (s.split_at(1).1, Tree { l, r })
-    }
-    fn parse_node(s: &str) -> (&str, Node) {
-        match s.chars().next().unwrap() {
-            '[' => {
-                let (s, tree) = Self::parse_tree(s);
-                (s, Node::Leaf(Box::new(tree)))
+    // on failure returns the remaining input and what was expected there
+    fn parse_tree(s: &str) -> Result<(&str, Tree), (&str, &'static str)> {
+        let s = Self::expect(s, '[')?;
+        let (s, l) = Self::parse_node(s)?;
+        let s = Self::expect(s, ',')?;
+        let (s, r) = Self::parse_node(s)?;
+        let s = Self::expect(s, ']')?;
+        Ok((s, Tree { l, r }))
+    }
+    fn parse_node(s: &str) -> Result<(&str, Node), (&str, &'static str)> {
+        match s.chars().next() {
+            Some('[') => {
+                let (s, tree) = Self::parse_tree(s)?;
+                Ok((s, Node::Leaf(Box::new(tree))))
             }
             _ => {
-                let numidx = s.chars().take_while(|x| x.to_digit(10).is_some()).count();
+                let numidx = s.chars().take_while(|x| x.is_ascii_digit()).count();
                 let (num, rest) = s.split_at(numidx);
-                (rest, Node::Num(num.parse().unwrap()))
+                match num.parse() {
+                    Ok(num) => Ok((rest, Node::Num(num))),
+                    Err(_) => Err((s, "a number or '['")),
+                }
             }
         }
     }
+    fn expect(s: &str, c: char) -> Result<&str, (&str, &'static str)> {
+        match s.strip_prefix(c) {
+            Some(rest) => Ok(rest),
+            None => Err((
+                s,
+                match c {
+                    '[' => "'['",
+                    ',' => "','",
+                    _ => "']'",
+                },
+            )),
+        }
+    }
 }
 
-fn solution1(input: &str) -> Result<String> {
-    let trees = input
-        .lines()
-        .map(|x| Node::Leaf(Box::new(Tree::parse_tree(x.trim()).1)))
-        .collect_vec();
+pub struct Day18;
 
-    let node = Node::add_all(trees);
-    Ok(format!("{}", node.magnitude()))
-}
+impl Day for Day18 {
+    type Input = Vec<Node>;
+
+    fn parse(input: &str) -> Result<Vec<Node>> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, x)| {
+                let error = |rest: &str, expected| {
+                    let column = x.len() - rest.len();
+                    ParseError::new(i + 1, x, column..column + 1, expected)
+                };
+                match Tree::parse_tree(x) {
+                    Ok(("", tree)) => Ok(Node::Leaf(Box::new(tree))),
+                    Ok((rest, _)) => Err(error(rest, "end of line").into()),
+                    Err((rest, expected)) => Err(error(rest, expected).into()),
+                }
+            })
+            .collect()
+    }
+
+    fn part1(trees: &Vec<Node>) -> Result<String> {
+        let node = Node::add_all(trees.clone());
+        Ok(format!("{}", node.magnitude()))
+    }
 
-fn solution2(input: &str) -> Result<String> {
-    let trees = input
-        .lines()
-        .map(|x| Node::Leaf(Box::new(Tree::parse_tree(x.trim()).1)))
-        .collect_vec();
-
-    let max = trees
-        .iter()
-        .tuple_combinations()
-        .map(|(x1, x2)| {
-            Node::add(x1.clone(), x2.clone())
-                .magnitude()
-                .max(Node::add(x2.clone(), x1.clone()).magnitude())
-        })
-        .max()
-        .unwrap();
-
-    Ok(format!("{}", max))
+    fn part2(trees: &Vec<Node>) -> Result<String> {
+        let max = trees
+            .iter()
+            .tuple_combinations()
+            .map(|(x1, x2)| {
+                Node::add(x1.clone(), x2.clone())
+                    .magnitude()
+                    .max(Node::add(x2.clone(), x1.clone()).magnitude())
+            })
+            .max()
+            .ok_or_else(|| anyhow!("Adding numbers needs at least two of them"))?;
+
+        Ok(format!("{}", max))
+    }
 }
 
+#[cfg(test)]
 mod tests {
-    use crate::day18::{solution1, solution2, Node, Tree};
+    use crate::assert_snapshot;
     use crate::run_solution;
+    use crate::util::ParseError;
+    use crate::y2021::day18::{Day18, Node, Tree};
+    use crate::Day;
     use indoc::indoc;
     use itertools::Itertools;
 
-    const INPUT: &'static str = "day18.txt";
+    const INPUT: &str = "2021/day18.txt";
 
     // PART 1
 
@@ -227,7 +263,7 @@ mod tests {
 [4,4]
 [5,5]"
             .lines()
-            .map(|x| Node::Leaf(Box::new(Tree::parse_tree(x.trim()).1)))
+            .map(|x| Node::Leaf(Box::new(Tree::parse_tree(x.trim()).unwrap().1)))
             .collect_vec();
 
         assert_eq!(
@@ -249,32 +285,44 @@ mod tests {
 [[[5,[7,4]],7],1]
 [[[[4,2],2],6],[8,7]]"
             .lines()
-            .map(|x| Node::Leaf(Box::new(Tree::parse_tree(x.trim()).1)))
+            .map(|x| Node::Leaf(Box::new(Tree::parse_tree(x.trim()).unwrap().1)))
             .collect_vec();
 
-        assert_eq!(
-            "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]",
-            format!("{}", Node::add_all(trees))
-        );
+        assert_snapshot!(Node::add_all(trees));
     }
 
     #[test]
     fn test_parse() {
-        assert_eq!("[1,2]", solution1(indoc!("[1,2]")).unwrap());
-        assert_eq!("[[1,2],3]", solution1(indoc!("[[1,2],3]")).unwrap());
-        assert_eq!(
+        for x in [
+            "[1,2]",
+            "[[1,2],3]",
             "[[[[1,3],[5,3]],[[1,3],[8,7]]],[[[4,9],[6,9]],[[8,2],[7,3]]]]",
-            solution1(indoc!(
-                "[[[[1,3],[5,3]],[[1,3],[8,7]]],[[[4,9],[6,9]],[[8,2],[7,3]]]]"
-            ))
-            .unwrap()
+        ] {
+            assert_eq!(x, format!("{}", Day18::parse(x).unwrap()[0]));
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = Day18::parse("[1,2]\n[[1,2],x]").err().unwrap();
+        let e = e.downcast::<ParseError>().unwrap();
+        assert_eq!((2, 7..8), (e.line, e.columns));
+        assert_eq!("a number or '['", e.expected);
+
+        let e = Day18::parse("[1,2]]").err().unwrap();
+        let e = e.downcast::<ParseError>().unwrap();
+        assert_eq!(
+            (1, 5..6, "end of line"),
+            (e.line, e.columns, e.expected.as_str())
         );
     }
 
     #[test]
     fn test_magnitude() {
         let tree = Node::Leaf(Box::new(
-            Tree::parse_tree("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]").1,
+            Tree::parse_tree("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]")
+                .unwrap()
+                .1,
         ));
 
         assert_eq!(3488, tree.magnitude());
@@ -283,7 +331,9 @@ mod tests {
     #[test]
     fn test_reduce() {
         let mut tree = Node::Leaf(Box::new(
-            Tree::parse_tree("[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]").1,
+            Tree::parse_tree("[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]")
+                .unwrap()
+                .1,
         ));
         tree.reduce();
         assert_eq!("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]", format!("{}", tree));
@@ -292,7 +342,9 @@ mod tests {
     #[test]
     fn test_split() {
         let mut tree = Node::Leaf(Box::new(
-            Tree::parse_tree("[[[[0,7],4],[15,[0,13]]],[1,1]]").1,
+            Tree::parse_tree("[[[[0,7],4],[15,[0,13]]],[1,1]]")
+                .unwrap()
+                .1,
         ));
         tree.split();
         assert_eq!("[[[[0,7],4],[[7,8],[0,13]]],[1,1]]", format!("{}", tree));
@@ -300,21 +352,27 @@ mod tests {
 
     #[test]
     fn test_explode() {
-        let mut tree = Node::Leaf(Box::new(Tree::parse_tree("[[[[[9,8],1],2],3],4]").1));
+        let mut tree = Node::Leaf(Box::new(
+            Tree::parse_tree("[[[[[9,8],1],2],3],4]").unwrap().1,
+        ));
         tree.explode(0);
         assert_eq!("[[[[0,9],2],3],4]", format!("{}", tree));
     }
 
     #[test]
     fn test_explode2() {
-        let mut tree = Node::Leaf(Box::new(Tree::parse_tree("[7,[6,[5,[4,[3,2]]]]]").1));
+        let mut tree = Node::Leaf(Box::new(
+            Tree::parse_tree("[7,[6,[5,[4,[3,2]]]]]").unwrap().1,
+        ));
         tree.explode(0);
         assert_eq!("[7,[6,[5,[7,0]]]]", format!("{}", tree));
     }
 
     #[test]
     fn test_explode3() {
-        let mut tree = Node::Leaf(Box::new(Tree::parse_tree("[[6,[5,[4,[3,2]]]],1]").1));
+        let mut tree = Node::Leaf(Box::new(
+            Tree::parse_tree("[[6,[5,[4,[3,2]]]],1]").unwrap().1,
+        ));
         tree.explode(0);
         assert_eq!("[[6,[5,[7,0]]],3]", format!("{}", tree));
     }
@@ -322,7 +380,9 @@ mod tests {
     #[test]
     fn test_explode4() {
         let mut tree = Node::Leaf(Box::new(
-            Tree::parse_tree("[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]").1,
+            Tree::parse_tree("[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]")
+                .unwrap()
+                .1,
         ));
         tree.explode(0);
         assert_eq!("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]", format!("{}", tree));
@@ -331,7 +391,9 @@ mod tests {
     #[test]
     fn test_explode5() {
         let mut tree = Node::Leaf(Box::new(
-            Tree::parse_tree("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]").1,
+            Tree::parse_tree("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]")
+                .unwrap()
+                .1,
         ));
         tree.explode(0);
         assert_eq!("[[3,[2,[8,0]]],[9,[5,[7,0]]]]", format!("{}", tree));
@@ -341,7 +403,7 @@ mod tests {
     fn test_part1() {
         assert_eq!(
             "4140",
-            solution1(indoc!(
+            Day18::solution1(indoc!(
                 "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
 [[[5,[2,8]],4],[5,[[9,9],0]]]
 [6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
@@ -359,18 +421,38 @@ mod tests {
 
     #[test]
     fn run_solution1() {
-        run_solution(INPUT, solution1).unwrap()
+        run_solution(INPUT, Day18::solution1).unwrap()
     }
 
     // PART 2
 
     #[test]
     fn test_part2() {
-        assert_eq!("??", solution2(indoc!("")).unwrap());
+        assert_eq!(
+            "3993",
+            Day18::solution2(indoc!(
+                "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]"
+            ))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_part2_single_number() {
+        assert!(Day18::solution2("[1,2]").is_err());
     }
 
     #[test]
     fn run_solution2() {
-        run_solution(INPUT, solution2).unwrap()
+        run_solution(INPUT, Day18::solution2).unwrap()
     }
 }
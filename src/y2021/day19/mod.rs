@@ -1,124 +1,53 @@
-use crate::util::{parse_lines, parse_lines_regex};
+use crate::util::geom::{rotations, Mat3, Vec3};
+use crate::util::{parse_regex, sections, ParseError};
+use crate::Day;
 use anyhow::Result;
-use itertools::{izip, Itertools};
-use std::fmt::{Display, Formatter};
+use itertools::Itertools;
 
 type HashSet<T> = rustc_hash::FxHashSet<T>;
 //type HashSet<T> = std::collections::HashSet<T>;
 
-impl Vec3 {
-    pub fn shift(&self, n: i32) -> Vec3 {
-        match n {
-            0 => Vec3(self.0, self.1, self.2),
-            1 => Vec3(self.0, self.2, self.1),
+pub struct Scanner {
+    pub pos: Vec3<i32>,
+    pub rot: Mat3,
 
-            2 => Vec3(self.1, self.0, self.2),
-            3 => Vec3(self.1, self.2, self.0),
-
-            4 => Vec3(self.2, self.0, self.1),
-            5 => Vec3(self.2, self.1, self.0),
-
-            _ => panic!("Invalid"),
-        }
-    }
-}
-
-#[derive(Copy, Clone, PartialEq, Eq, Hash)]
-struct Vec3(i32, i32, i32);
-
-impl std::ops::Add for Vec3 {
-    type Output = Vec3;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        Vec3(self.0 + rhs.0, self.1 + rhs.1, self.2 + rhs.2)
-    }
-}
-
-impl std::ops::Sub for Vec3 {
-    type Output = Vec3;
-
-    fn sub(self, rhs: Self) -> Self::Output {
-        Vec3(self.0 - rhs.0, self.1 - rhs.1, self.2 - rhs.2)
-    }
-}
-
-impl std::ops::Mul for Vec3 {
-    type Output = Vec3;
-
-    fn mul(self, rhs: Self) -> Self::Output {
-        Vec3(self.0 * rhs.0, self.1 * rhs.1, self.2 * rhs.2)
-    }
-}
-
-impl Display for Vec3 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "[{},{},{}]", self.0, self.1, self.2)
-    }
-}
-
-struct Scanner {
-    pub pos: Vec3,
-    pub rot: Vec3,
-    pub shift: i32,
-
-    pub beacons: HashSet<Vec3>, // those are relative to the 0 scanner
+    pub beacons: HashSet<Vec3<i32>>, // those are relative to the 0 scanner
 }
 
 impl Scanner {
-    fn zero(beacons: HashSet<Vec3>) -> Scanner {
+    pub fn zero(beacons: HashSet<Vec3<i32>>) -> Scanner {
         Scanner {
-            rot: Vec3(1, 1, 1),
+            rot: Mat3::IDENTITY,
             pos: Vec3(0, 0, 0),
-            shift: 0,
             beacons,
         }
     }
 
-    fn normalize(pos: Vec3, rot: Vec3, shift: i32, beacon: Vec3) -> Vec3 {
-        beacon.shift(shift) * rot + pos
+    fn normalize(pos: Vec3<i32>, rot: Mat3, beacon: Vec3<i32>) -> Vec3<i32> {
+        rot * beacon + pos
     }
 
-    fn align(&self, detected_beacons: &HashSet<Vec3>) -> Option<Scanner> {
-        let rotations = vec![
-            Vec3(1, 1, 1),
-            Vec3(-1, 1, 1),
-            Vec3(1, -1, 1),
-            Vec3(-1, -1, 1),
-            Vec3(1, 1, -1),
-            Vec3(-1, 1, -1),
-            Vec3(1, -1, -1),
-            Vec3(-1, -1, -1),
-        ];
-
-        for rot in rotations {
+    pub fn align(&self, detected_beacons: &HashSet<Vec3<i32>>) -> Option<Scanner> {
+        for rot in rotations() {
             for &alignment_beacon in detected_beacons {
                 for &self_alignment in &self.beacons {
-                    for shift in 0..6 {
-                        // assumption alignment_beacon == self_alignment
-
-                        let alignment_beacon = alignment_beacon.shift(shift);
-
-                        let pos = self_alignment - alignment_beacon * rot;
-                        let intersections = detected_beacons
-                            .iter()
-                            .filter(|x| {
-                                self.beacons
-                                    .contains(&Scanner::normalize(pos, rot, shift, **x))
-                            })
-                            .count();
-
-                        assert!(intersections >= 1);
-                        if intersections >= 12 {
-                            return Some(Scanner {
-                                pos,
-                                beacons: detected_beacons
-                                    .iter()
-                                    .map(|x| Scanner::normalize(pos, rot, shift, *x))
-                                    .collect(),
-                                shift,
-                                rot,
-                            });
-                        }
+                    // assumption alignment_beacon == self_alignment
+                    let pos = self_alignment - rot * alignment_beacon;
+                    let intersections = detected_beacons
+                        .iter()
+                        .filter(|x| self.beacons.contains(&Scanner::normalize(pos, rot, **x)))
+                        .count();
+
+                    assert!(intersections >= 1);
+                    if intersections >= 12 {
+                        return Some(Scanner {
+                            pos,
+                            beacons: detected_beacons
+                                .iter()
+                                .map(|x| Scanner::normalize(pos, rot, *x))
+                                .collect(),
+                            rot,
+                        });
                     }
                 }
             }
@@ -128,34 +57,13 @@ impl Scanner {
     }
 }
 
-fn read_list(s: &str) -> HashSet<Vec3> {
-    s.trim()
-        .lines()
-        .map(|x| {
-            let vec = x.trim().split(',').collect_vec();
-            Vec3(
-                vec[0].parse().unwrap(),
-                vec[1].parse().unwrap(),
-                vec[2].parse().unwrap(),
-            )
-        })
-        .collect()
+fn read_list(s: &str) -> Result<HashSet<Vec3<i32>>, ParseError> {
+    let xs = parse_regex::<(i32, i32, i32)>(s.trim_end(), r"^(-?[0-9]+),(-?[0-9]+),(-?[0-9]+)$")?;
+    Ok(xs.into_iter().map(Vec3::from).collect())
 }
 
-fn solution1(input: &str) -> Result<String> {
-    let mut r: Vec<HashSet<Vec3>> = vec![];
-    let mut c: String = "".to_string();
-    for l in input.lines().skip(1) {
-        if l.starts_with("---") {
-            r.push(read_list(&c));
-            c = String::new();
-        } else {
-            c += l;
-            c += "\n";
-        }
-    }
-    r.push(read_list(&c));
-
+pub fn locate_scanners(scanners: &[HashSet<Vec3<i32>>]) -> Vec<Scanner> {
+    let mut r = scanners.to_vec();
     let mut finished = vec![Scanner::zero(r.remove(0))];
 
     loop {
@@ -174,79 +82,71 @@ fn solution1(input: &str) -> Result<String> {
                 .collect_vec();
         }
 
-        if new_finished.len() == 0 {
+        if new_finished.is_empty() {
             break;
         }
         finished.append(&mut new_finished);
     }
 
-    Ok(format!(
-        "{}",
-        finished
-            .iter()
-            .flat_map(|x| x.beacons.clone())
-            .unique()
-            .count()
-    ))
+    finished
 }
 
-pub(crate) fn solution2(input: &str) -> Result<String> {
-    let mut r: Vec<HashSet<Vec3>> = vec![];
-    let mut c: String = "".to_string();
-    for l in input.lines().skip(1) {
-        if l.starts_with("---") {
-            r.push(read_list(&c));
-            c = String::new();
-        } else {
-            c += l;
-            c += "\n";
-        }
-    }
-    r.push(read_list(&c));
+pub struct Day19;
 
-    let mut finished = vec![Scanner::zero(r.remove(0))];
-
-    loop {
-        let mut new_finished: Vec<Scanner> = Vec::new();
+impl Day for Day19 {
+    type Input = Vec<HashSet<Vec3<i32>>>;
 
-        for scanner in &finished {
-            r = r
-                .into_iter()
-                .filter(|x| match scanner.align(x) {
-                    None => true,
-                    Some(successes) => {
-                        new_finished.push(successes);
-                        false
+    fn parse(input: &str) -> Result<Vec<HashSet<Vec3<i32>>>> {
+        Ok(sections(input)
+            .iter()
+            .map(|s| {
+                s.parse(|s| {
+                    let (header, beacons) = s.split_once('\n').unwrap_or((s, ""));
+                    if !header.starts_with("---") {
+                        return Err(ParseError::whole_line(1, header, "a scanner header"));
                     }
+                    read_list(beacons).map_err(|e| e.offset(1))
                 })
-                .collect_vec();
-        }
+            })
+            .try_collect()?)
+    }
 
-        if new_finished.len() == 0 {
-            break;
-        }
-        finished.append(&mut new_finished);
+    fn part1(scanners: &Vec<HashSet<Vec3<i32>>>) -> Result<String> {
+        let finished = locate_scanners(scanners);
+
+        Ok(format!(
+            "{}",
+            finished
+                .iter()
+                .flat_map(|x| x.beacons.clone())
+                .unique()
+                .count()
+        ))
     }
 
-    let max = finished
-        .iter()
-        .tuple_combinations()
-        .map(|(x1, x2)| {
-            let dis: Vec3 = x1.pos - x2.pos;
-            dis.0.abs() + dis.1.abs() + dis.2.abs()
-        })
-        .max()
-        .unwrap();
+    fn part2(scanners: &Vec<HashSet<Vec3<i32>>>) -> Result<String> {
+        let finished = locate_scanners(scanners);
+
+        let max = finished
+            .iter()
+            .tuple_combinations()
+            .map(|(x1, x2)| x1.pos.manhattan(x2.pos))
+            .max()
+            .unwrap();
 
-    Ok(format!("{}", max))
+        Ok(format!("{}", max))
+    }
 }
 
+#[cfg(test)]
 mod tests {
-    use crate::day19::{read_list, solution1, solution2, Scanner, Vec3};
     use crate::run_solution;
+    use crate::util::geom::Vec3;
+    use crate::y2021::day19::{read_list, Day19, Scanner};
+    use crate::Day;
     use indoc::indoc;
 
-    const INPUT: &'static str = "day19.txt";
+    const INPUT: &str = "2021/day19.txt";
 
     // PART 1
 
@@ -278,7 +178,8 @@ mod tests {
 443,580,662
 -789,900,-551
 459,-707,401",
-        );
+        )
+        .unwrap();
 
         let l2 = read_list(
             "686,422,578
@@ -306,7 +207,8 @@ mod tests {
 807,-499,-711
 755,-354,-619
 553,889,-390",
-        );
+        )
+        .unwrap();
 
         let l4 = read_list(
             "727,592,562
@@ -335,7 +237,8 @@ mod tests {
 891,-625,532
 -652,-548,-490
 30,-46,-14",
-        );
+        )
+        .unwrap();
 
         let zero = Scanner::zero(l1);
         let scanner2 = zero.align(&l2).unwrap();
@@ -345,12 +248,7 @@ mod tests {
             "[-618,-824,-621]",
             format!(
                 "{}",
-                Scanner::normalize(
-                    scanner2.pos,
-                    scanner2.rot,
-                    scanner2.shift,
-                    Vec3(686, 422, 578)
-                )
+                Scanner::normalize(scanner2.pos, scanner2.rot, Vec3(686, 422, 578))
             )
         );
 
@@ -362,7 +260,7 @@ mod tests {
     fn test_part1() {
         assert_eq!(
             "79",
-            solution1(indoc!(
+            Day19::solution1(indoc!(
                 "--- scanner 0 ---
 404,-588,-901
 528,-643,409
@@ -506,7 +404,7 @@ mod tests {
 
     #[test]
     fn run_solution1() {
-        run_solution(INPUT, solution1).unwrap()
+        run_solution(INPUT, Day19::solution1).unwrap()
     }
 
     // PART 2
@@ -514,7 +412,7 @@ mod tests {
     fn test_part2() {
         assert_eq!(
             "3621",
-            solution2(indoc!(
+            Day19::solution2(indoc!(
                 "--- scanner 0 ---
 404,-588,-901
 528,-643,409
@@ -658,6 +556,6 @@ mod tests {
 
     #[test]
     fn run_solution2() {
-        run_solution(INPUT, solution2).unwrap()
+        run_solution(INPUT, Day19::solution2).unwrap()
     }
 }
@@ -0,0 +1,84 @@
+use crate::util::parse_regex;
+use crate::Day;
+use anyhow::Result;
+
+pub struct Day2;
+
+impl Day for Day2 {
+    type Input = Vec<(String, i32)>;
+
+    fn parse(input: &str) -> Result<Vec<(String, i32)>> {
+        Ok(parse_regex(input, "^(forward|down|up) ([0-9]+)$")?)
+    }
+
+    fn part1(xs: &Vec<(String, i32)>) -> Result<String> {
+        let mut hor = 0;
+        let mut vert = 0;
+
+        xs.iter().for_each(|(dir, am)| match dir.as_str() {
+            "forward" => hor += am,
+            "down" => vert += am,
+            "up" => vert -= am,
+            _ => panic!(),
+        });
+
+        Ok(format!("{}", hor * vert))
+    }
+
+    fn part2(xs: &Vec<(String, i32)>) -> Result<String> {
+        let mut hor = 0;
+        let mut vert = 0;
+        let mut aim = 0;
+
+        xs.iter().for_each(|(dir, am)| match dir.as_str() {
+            "forward" => {
+                hor += am;
+                vert += am * aim;
+            }
+            "down" => aim += am,
+            "up" => aim -= am,
+            _ => panic!(),
+        });
+
+        Ok(format!("{}", hor * vert))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2021::day2::Day2;
+    use crate::Day;
+    use indoc::indoc;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            Day2::solution1(indoc!(
+                "forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2"
+            ))
+            .unwrap(),
+            "150"
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            Day2::solution2(indoc!(
+                "forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2"
+            ))
+            .unwrap(),
+            "900"
+        );
+    }
+}
@@ -0,0 +1,279 @@
+use crate::util::grid::Grid;
+use crate::util::{sections, ParseError};
+use crate::viz::{Cell, Frame, Frames};
+use crate::{debug, Day};
+use anyhow::Result;
+use bit_set::BitSet;
+use itertools::Itertools;
+use std::fmt::{Display, Formatter};
+
+pub struct ImageEnhancementAlgorithm {
+    bits: BitSet,
+    len: usize,
+}
+
+impl ImageEnhancementAlgorithm {
+    pub fn new(s: &str) -> ImageEnhancementAlgorithm {
+        assert_eq!(512, s.len());
+        let mut bits = BitSet::with_capacity(s.len());
+        for (i, c) in s.chars().enumerate() {
+            if c == '#' {
+                bits.insert(i);
+            }
+        }
+        ImageEnhancementAlgorithm { bits, len: s.len() }
+    }
+
+    pub fn get_pixel(&self, i: usize) -> bool {
+        self.bits.contains(i)
+    }
+}
+
+impl Display for ImageEnhancementAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for i in 0..self.len {
+            write!(f, "{}", if self.bits.contains(i) { '#' } else { '.' })?
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+pub struct Image {
+    pixels: Grid<bool>,
+    infinity_value: bool,
+}
+
+impl Image {
+    pub fn is_set(&self, x: usize, y: usize) -> bool {
+        self.pixels[(x, y)]
+    }
+
+    pub fn get_9_pixel_around(&self, x: usize, y: usize) -> u32 {
+        let mut r = 0;
+        for y_del in -1..=1 {
+            for x_del in -1..=1 {
+                let set = match self.pixels.offset((x, y), (x_del, y_del)) {
+                    Some(p) => self.pixels[p],
+                    None => self.infinity_value,
+                };
+                r = r << 1 | set as u32;
+            }
+        }
+        r
+    }
+
+    pub fn apply(&self, algo: &ImageEnhancementAlgorithm) -> Image {
+        let pixels = Grid::from_fn(self.pixels.width(), self.pixels.height(), |(x, y)| {
+            algo.get_pixel(self.get_9_pixel_around(x, y) as usize)
+        });
+        let infinity_value = pixels[(0, 0)];
+
+        Image {
+            pixels,
+            infinity_value,
+        }
+    }
+
+    pub fn with_margin(&self, margin: usize) -> Image {
+        let new_width = self.pixels.width() + 2 * margin;
+        let new_height = self.pixels.height() + 2 * margin;
+
+        let pixels = Grid::from_fn(new_width, new_height, |(x, y)| {
+            x >= margin && y >= margin && self.pixels.get((x - margin, y - margin)) == Some(&true)
+        });
+
+        Image {
+            pixels,
+            infinity_value: false,
+        }
+    }
+
+    pub fn from_lines(s: &str) -> Image {
+        let rows = s
+            .trim()
+            .lines()
+            .map(|line| line.trim().chars().map(|c| c == '#').collect_vec())
+            .collect_vec();
+
+        Image {
+            pixels: Grid::from_rows(rows),
+            infinity_value: false,
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.pixels.values().filter(|&&x| x).count()
+    }
+}
+
+impl Display for Image {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.pixels.map(|&x| if x { '#' } else { '.' }))
+    }
+}
+
+// the image after each of the 50 enhancements
+pub fn frames(input: &str) -> Result<Frames> {
+    let (algo, img) = Day20::parse(input)?;
+    let frame = |img: &Image, step: usize| Frame {
+        caption: format!("enhancement {}, {} lit", step, img.count()),
+        cells: img.pixels.map(|&x| match x {
+            true => Cell::heat('#', 9),
+            false => Cell::plain('.'),
+        }),
+    };
+    // enough room for the image to grow by one pixel per step
+    let mut img = img.with_margin(51);
+    let mut step = 0;
+    Ok(Box::new(std::iter::from_fn(move || {
+        if step > 50 {
+            return None;
+        }
+        if step > 0 {
+            img = img.apply(&algo);
+        }
+        step += 1;
+        Some(frame(&img, step - 1))
+    })))
+}
+
+pub struct Day20;
+
+impl Day for Day20 {
+    type Input = (ImageEnhancementAlgorithm, Image);
+
+    fn parse(input: &str) -> Result<(ImageEnhancementAlgorithm, Image)> {
+        let pixel = |c: char| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        };
+
+        let first = input.lines().next().unwrap_or("");
+        Grid::parse(first, "'#' or '.'", pixel)?;
+        if first.len() != 512 {
+            return Err(ParseError::whole_line(1, first, "512 pixels").into());
+        }
+
+        let rest = input.split_once('\n').map_or("", |x| x.1);
+        let pixels = match sections(rest).first() {
+            Some(s) => s
+                .parse(|s| Grid::parse(s, "'#' or '.'", pixel))
+                .map_err(|e| e.offset(1))?,
+            None => return Err(ParseError::new(3, "", 0..0, "an image").into()),
+        };
+
+        let algo = ImageEnhancementAlgorithm::new(first);
+        let img = Image {
+            pixels,
+            infinity_value: false,
+        };
+
+        Ok((algo, img))
+    }
+
+    fn part1((algo, img): &(ImageEnhancementAlgorithm, Image)) -> Result<String> {
+        let img = img.with_margin(10);
+        debug!("\n{}", img);
+        let img = img.apply(algo);
+        debug!("\n{}", img);
+        let img = img.apply(algo);
+        debug!("\n{}", img);
+
+        Ok(format!("{}", img.count()))
+    }
+
+    fn part2((algo, img): &(ImageEnhancementAlgorithm, Image)) -> Result<String> {
+        let mut img = img.with_margin(120);
+
+        // a fixed number of steps that never looks for a cycle, so it is left a plain loop
+        // rather than a util::sim::Sim
+        for _ in 0..50 {
+            img = img.apply(algo);
+        }
+
+        debug!("\n{}", img);
+
+        Ok(format!("{}", img.count()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_snapshot;
+    use crate::run_solution;
+    use crate::y2021::day20::{Day20, Image, ImageEnhancementAlgorithm};
+    use crate::Day;
+    use indoc::indoc;
+
+    const INPUT: &str = "2021/day20.txt";
+
+    // PART 1
+
+    #[test]
+    fn test_d9() {
+        let img = "#..#.
+#....
+##..#
+..#..
+..###";
+        let image = Image::from_lines(img);
+        assert_eq!(34, image.get_9_pixel_around(2, 2));
+    }
+    #[test]
+    fn test_display() {
+        let img = "#..#.
+#....
+##..#
+..#..
+..###";
+        let algo = "..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#";
+
+        assert_eq!(img, format!("{}", Image::from_lines(img)).trim());
+        assert_eq!(algo, format!("{}", ImageEnhancementAlgorithm::new(algo)));
+    }
+
+    #[test]
+    fn test_with_margin() {
+        let img = "#..#.
+#....
+##..#
+..#..
+..###";
+
+        assert_snapshot!(Image::from_lines(img).with_margin(2));
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!("35", Day20::solution1(indoc!("..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
+        #..#.
+        #....
+        ##..#
+        ..#..
+        ..###")).unwrap());
+    }
+
+    #[test]
+    fn run_solution1() {
+        run_solution(INPUT, Day20::solution1).unwrap()
+    }
+
+    // PART 2
+
+    #[test]
+    fn test_part2() {
+        assert_eq!("3351", Day20::solution2(indoc!("..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
+        #..#.
+        #....
+        ##..#
+        ..#..
+        ..###")).unwrap());
+    }
+
+    #[test]
+    fn run_solution2() {
+        run_solution(INPUT, Day20::solution2).unwrap()
+    }
+}
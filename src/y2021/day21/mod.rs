@@ -0,0 +1,223 @@
+use crate::util::memo::Memo;
+use crate::util::{parse_lines_regex, ParseError};
+use crate::{debug, trace, Day};
+use anyhow::Result;
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+struct Player {
+    position: usize,
+    score: usize,
+}
+
+impl Player {
+    fn new(position: usize) -> Player {
+        Player { position, score: 0 }
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+struct Game {
+    dice: usize,
+    dice_size: usize,
+    board_size: usize,
+    dice_rolls_count: usize,
+}
+
+impl Game {
+    fn roll(&mut self, p: &mut Player) -> bool {
+        let mut pos_d = 0;
+        pos_d += self.dice;
+        self.dice += 1;
+        pos_d += self.dice;
+        self.dice += 1;
+        pos_d += self.dice;
+        self.dice += 1;
+        self.dice_rolls_count += 3;
+
+        p.position += pos_d;
+        p.position %= self.board_size;
+
+        self.dice %= self.dice_size;
+
+        p.score += p.position + 1;
+
+        p.score >= 1000
+    }
+
+    fn roll_game(mut g: Game, mut p: Player, turn: usize) -> (Game, Player) {
+        g.dice_rolls_count += 1;
+
+        p.position += g.dice;
+        p.position %= g.board_size;
+
+        if turn == 2 || turn == 5 {
+            p.score += p.position + 1;
+        }
+
+        (g, p)
+    }
+}
+
+type GameMemo = Memo<(usize, Game, Player, Player), (u64, u64)>;
+
+fn play_rec(
+    memo: &mut GameMemo,
+    game: Game,
+    mut p1: Player,
+    mut p2: Player,
+    turn: usize,
+) -> (u64, u64) {
+    memo.get((turn, game, p1, p2), |memo| {
+        let win_at = 21;
+
+        if turn < 3 {
+            let (_, p1_new) = Game::roll_game(game, p1, turn);
+            p1 = p1_new;
+            if p1.score >= win_at {
+                return (1, 0);
+            }
+        } else {
+            let (_, p2_new) = Game::roll_game(game, p2, turn);
+            p2 = p2_new;
+            if p2.score >= win_at {
+                return (0, 1);
+            }
+        }
+
+        let turn = (turn + 1) % 6;
+
+        let mut p1_cnt = 0;
+        let mut p2_cnt = 0;
+        for x in 1..=3 {
+            let mut game = game;
+            game.dice = x;
+            let r = play_rec(memo, game, p1, p2, turn);
+            p1_cnt += r.0;
+            p2_cnt += r.1;
+        }
+        (p1_cnt, p2_cnt)
+    })
+}
+
+pub struct Day21;
+
+impl Day for Day21 {
+    type Input = (usize, usize);
+
+    fn parse(input: &str) -> Result<(usize, usize)> {
+        let xs = parse_lines_regex(input, "^Player [12] starting position: ([0-9]+)$")?;
+        let positions = xs
+            .iter()
+            .map(|l| match l.parse::<usize>(1)? {
+                x @ 1..=10 => Ok(x),
+                _ => Err(l.error(1, "a position between 1 and 10")),
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        match positions.as_slice() {
+            &[p1, p2] => Ok((p1, p2)),
+            _ => Err(ParseError::new(xs.len() + 1, "", 0..0, "exactly two players").into()),
+        }
+    }
+
+    fn part1(&(p1_start, p2_start): &(usize, usize)) -> Result<String> {
+        let mut p1 = Player::new(p1_start - 1);
+        let mut p2 = Player::new(p2_start - 1);
+        let mut game = Game {
+            dice: 1,
+            dice_size: 100,
+            board_size: 10,
+            dice_rolls_count: 0,
+        };
+
+        loop {
+            if game.roll(&mut p1) || game.roll(&mut p2) {
+                break;
+            }
+            trace!(
+                "dice={} p1=({}, {}) p2=({}, {})",
+                game.dice,
+                p1.position + 1,
+                p1.score,
+                p2.position + 1,
+                p2.score
+            );
+        }
+
+        Ok(format!(
+            "{}",
+            p1.score.min(p2.score) * game.dice_rolls_count
+        ))
+    }
+
+    fn part2(&(p1_start, p2_start): &(usize, usize)) -> Result<String> {
+        let p1 = Player::new(p1_start - 1);
+        let p2 = Player::new(p2_start - 1);
+
+        let mut r = (0, 0);
+        for x in 1..=3 {
+            let game = Game {
+                dice: x,
+                dice_size: 3,
+                board_size: 10,
+                dice_rolls_count: 0,
+            };
+
+            let mut memo = GameMemo::new();
+            let rr = play_rec(&mut memo, game, p1, p2, 0);
+            debug!("game cache: {}", memo.stats());
+            r.0 += rr.0;
+            r.1 += rr.1;
+        }
+
+        Ok(format!("{}", r.0.max(r.1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::run_solution;
+    use crate::y2021::day21::Day21;
+    use crate::Day;
+    use indoc::indoc;
+
+    const INPUT: &str = "2021/day21.txt";
+
+    // PART 1
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            "739785",
+            Day21::solution1(indoc!(
+                "Player 1 starting position: 4
+                Player 2 starting position: 8"
+            ))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn run_solution1() {
+        run_solution(INPUT, Day21::solution1).unwrap()
+    }
+
+    // PART 2
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            "444356092776315",
+            Day21::solution2(indoc!(
+                "Player 1 starting position: 4
+                Player 2 starting position: 8"
+            ))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn run_solution2() {
+        run_solution(INPUT, Day21::solution2).unwrap()
+    }
+}
@@ -0,0 +1,215 @@
+use crate::util::geom::{Cuboid, Vec3};
+use crate::util::parse_regex;
+use crate::Day;
+use anyhow::Result;
+use itertools::Itertools;
+
+type HashSet<T> = rustc_hash::FxHashSet<T>;
+
+struct CubeStack {}
+
+impl CubeStack {
+    fn volume(stack: &[Step]) -> u64 {
+        let mut area = 0;
+        let mut counted: Vec<&Cuboid<i32>> = Default::default();
+        for (on, cube) in stack.iter().rev() {
+            if *on {
+                area += Self::get_additional_volume(cube, &counted);
+            }
+            counted.push(cube);
+        }
+        area
+    }
+
+    fn get_additional_volume(x: &Cuboid<i32>, counted: &Vec<&Cuboid<i32>>) -> u64 {
+        let mut volume = x.volume();
+
+        let intersections = counted
+            .iter()
+            .filter_map(|c| x.intersection(c))
+            .collect_vec();
+        let mut removed: Vec<&Cuboid<i32>> = Default::default();
+        for intersection in intersections.iter() {
+            volume -= Self::get_additional_volume(intersection, &removed);
+            removed.push(intersection);
+        }
+
+        volume
+    }
+}
+
+pub type Step = (bool, Cuboid<i32>);
+
+// switches every single cube inside of -limit..=limit
+pub fn count_voxels(steps: &[Step], limit: i32) -> usize {
+    let mut cubes: HashSet<Vec3<i32>> = Default::default();
+    let region = Cuboid::inclusive(Vec3(-limit, -limit, -limit), Vec3(limit, limit, limit));
+
+    steps
+        .iter()
+        .filter_map(|(on, cuboid)| Some((on, cuboid.intersection(&region)?)))
+        .for_each(|(on, cuboid)| {
+            for p in cuboid.points() {
+                if *on {
+                    cubes.insert(p);
+                } else {
+                    cubes.remove(&p);
+                }
+            }
+        });
+
+    cubes.len()
+}
+
+pub fn volume(steps: &[Step]) -> u64 {
+    CubeStack::volume(steps)
+}
+
+pub struct Day22;
+
+impl Day for Day22 {
+    type Input = Vec<Step>;
+
+    fn parse(input: &str) -> Result<Vec<Step>> {
+        let xs = parse_regex::<(String, Cuboid<i32>)>(input, r"^(on|off) (.+)$")?;
+
+        Ok(xs
+            .into_iter()
+            .map(|(on_off, cuboid)| (on_off == "on", cuboid))
+            .collect())
+    }
+
+    fn part1(steps: &Vec<Step>) -> Result<String> {
+        Ok(format!("{:?}", count_voxels(steps, 50)))
+    }
+
+    fn part2(steps: &Vec<Step>) -> Result<String> {
+        Ok(format!("{:?}", volume(steps)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gen::check::Checker;
+    use crate::gen::reboot_steps;
+    use crate::run_solution;
+    use crate::util::geom::{Cuboid, Vec3};
+    use crate::y2021::day22::{count_voxels, volume, Day22};
+    use crate::Day;
+    use indoc::indoc;
+    use itertools::Itertools;
+
+    const INPUT: &str = "2021/day22.txt";
+
+    // PART 1
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            "590784",
+            Day22::solution1(indoc!(
+                "on x=-20..26,y=-36..17,z=-47..7
+on x=-20..33,y=-21..23,z=-26..28
+on x=-22..28,y=-29..23,z=-38..16
+on x=-46..7,y=-6..46,z=-50..-1
+on x=-49..1,y=-3..46,z=-24..28
+on x=2..47,y=-22..22,z=-23..27
+on x=-27..23,y=-28..26,z=-21..29
+on x=-39..5,y=-6..47,z=-3..44
+on x=-30..21,y=-8..43,z=-13..34
+on x=-22..26,y=-27..20,z=-29..19
+off x=-48..-32,y=26..41,z=-47..-37
+on x=-12..35,y=6..50,z=-50..-2
+off x=-48..-32,y=-32..-16,z=-15..-5
+on x=-18..26,y=-33..15,z=-7..46
+off x=-40..-22,y=-38..-28,z=23..41
+on x=-16..35,y=-41..10,z=-47..6
+off x=-32..-23,y=11..30,z=-14..3
+on x=-49..-5,y=-3..45,z=-29..18
+off x=18..30,y=-20..-8,z=-3..13
+on x=-41..9,y=-7..43,z=-33..15
+on x=-54112..-39298,y=-85059..-49293,z=-27449..7877
+on x=967..23432,y=45373..81175,z=27513..53682"
+            ))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn run_solution1() {
+        run_solution(INPUT, Day22::solution1).unwrap()
+    }
+
+    // PART 2
+
+    #[test]
+    fn test_part2() {
+        // the 590784 cubes of part 1 and the two large cuboids at the end, which overlap nothing
+        assert_eq!(
+            "39769202357779",
+            Day22::solution2(indoc!(
+                "on x=-20..26,y=-36..17,z=-47..7
+on x=-20..33,y=-21..23,z=-26..28
+on x=-22..28,y=-29..23,z=-38..16
+on x=-46..7,y=-6..46,z=-50..-1
+on x=-49..1,y=-3..46,z=-24..28
+on x=2..47,y=-22..22,z=-23..27
+on x=-27..23,y=-28..26,z=-21..29
+on x=-39..5,y=-6..47,z=-3..44
+on x=-30..21,y=-8..43,z=-13..34
+on x=-22..26,y=-27..20,z=-29..19
+off x=-48..-32,y=26..41,z=-47..-37
+on x=-12..35,y=6..50,z=-50..-2
+off x=-48..-32,y=-32..-16,z=-15..-5
+on x=-18..26,y=-33..15,z=-7..46
+off x=-40..-22,y=-38..-28,z=23..41
+on x=-16..35,y=-41..10,z=-47..6
+off x=-32..-23,y=11..30,z=-14..3
+on x=-49..-5,y=-3..45,z=-29..18
+off x=18..30,y=-20..-8,z=-3..13
+on x=-41..9,y=-7..43,z=-33..15
+on x=-54112..-39298,y=-85059..-49293,z=-27449..7877
+on x=967..23432,y=45373..81175,z=27513..53682"
+            ))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_part222() {
+        assert_eq!(
+            "39",
+            Day22::solution2(indoc!(
+                "on x=10..12,y=10..12,z=10..12
+on x=11..13,y=11..13,z=11..13
+off x=9..11,y=9..11,z=9..11
+on x=10..10,y=10..10,z=10..10"
+            ))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn run_solution2() {
+        run_solution(INPUT, Day22::solution2).unwrap()
+    }
+
+    #[test]
+    fn test_differential() {
+        // some generated steps lie far outside of the region that part 1 counts
+        let region = Cuboid::inclusive(Vec3(-50, -50, -50), Vec3(50, 50, 50));
+        Checker::default().assert_agree::<Day22, _>(
+            reboot_steps,
+            5,
+            0,
+            |steps, _| {
+                let clipped = steps
+                    .iter()
+                    .filter_map(|(on, cuboid)| Some((*on, cuboid.intersection(&region)?)))
+                    .collect_vec();
+                volume(&clipped)
+            },
+            |steps, _| count_voxels(steps, 50) as u64,
+        );
+    }
+}
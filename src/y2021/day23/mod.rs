@@ -1,18 +1,21 @@
-use crate::util::{parse_lines, parse_lines_regex};
+use crate::util::search::{dijkstra, Search, Successors};
+use crate::util::ParseError;
+use crate::{debug, Day};
 use anyhow::Result;
-use bit_set::BitSet;
 use enumset::{EnumSet, EnumSetType};
-use itertools::{izip, Itertools};
+use itertools::Itertools;
 use std::fmt::{Display, Formatter};
-use std::mem::{swap, transmute};
+use std::hash::{Hash, Hasher};
+use std::mem::swap;
 use Amphipod::*;
 use Location::*;
 
 type HashMap<T, V> = rustc_hash::FxHashMap<T, V>;
-type HashSet<T> = rustc_hash::FxHashSet<T>;
 
+// EnumSetType compares by discriminant, which is what the derived Hash hashes
+#[allow(clippy::derived_hash_with_manual_eq)]
 #[derive(Debug, Hash, EnumSetType)]
-enum Location {
+pub enum Location {
     AL2,
     AL1,
     AB,
@@ -42,14 +45,24 @@ enum Location {
 
 impl Location {
     fn is_room(&self) -> bool {
-        match self {
-            RA1 | RA2 | RB1 | RB2 | RC1 | RC2 | RD1 | RD2 | RA3 | RA4 | RB3 | RB4 | RC3 | RC4
-            | RD3 | RD4 => true,
-            _ => false,
-        }
-    }
-    fn is_hallway(&self) -> bool {
-        !self.is_room()
+        matches!(
+            self,
+            RA1 | RA2
+                | RB1
+                | RB2
+                | RC1
+                | RC2
+                | RD1
+                | RD2
+                | RA3
+                | RA4
+                | RB3
+                | RB4
+                | RC3
+                | RC4
+                | RD3
+                | RD4
+        )
     }
 
     fn get_rooms_in_order_for_room(&self) -> Vec<Location> {
@@ -63,34 +76,10 @@ impl Location {
             _ => panic!(""),
         }
     }
-
-    fn get_first_room_if_is_second(&self) -> Option<Location> {
-        match self {
-            RA2 => Some(RA1),
-            RB2 => Some(RB1),
-            RC2 => Some(RC1),
-            RD2 => Some(RD1),
-            _ => None,
-        }
-    }
-
-    fn get_second_room_if_is_first(&self) -> Option<Location> {
-        match self {
-            RA1 => Some(RA2),
-            RB1 => Some(RB2),
-            RC1 => Some(RC2),
-            RD1 => Some(RD2),
-            _ => None,
-        }
-    }
-
-    fn is_first_room(&self) -> bool {
-        self.get_second_room_if_is_first().is_some()
-    }
 }
 
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-enum Amphipod {
+pub enum Amphipod {
     A,
     B,
     C,
@@ -98,6 +87,15 @@ enum Amphipod {
 }
 
 impl Amphipod {
+    fn energy(&self) -> u32 {
+        match self {
+            A => 1,
+            B => 10,
+            C => 100,
+            D => 1000,
+        }
+    }
+
     fn target_room2(&self) -> Location {
         match self {
             A => RA2,
@@ -108,31 +106,40 @@ impl Amphipod {
     }
 }
 
-enum Room {
-    A,
-    B,
-    C,
-    D,
-}
-
 type LocationSet = EnumSet<Location>;
 
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 struct State {
     locations: HashMap<Location, Amphipod>,
-    score: u32,
+    // the places per room, 2 in part 1 and 4 in part 2
+    depth: usize,
+}
+
+impl Hash for State {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // the iteration order of the map depends on how it was filled
+        for (l, a) in self.locations.iter().sorted_by_key(|(l, _)| **l as u8) {
+            (l, a).hash(state);
+        }
+    }
 }
 
 impl State {
+    // the rooms are full at the start
     fn new(ls: Vec<(Location, Amphipod)>) -> State {
+        let depth = ls.len() / 4;
         let mut locations: HashMap<Location, Amphipod> = Default::default();
         for (l, a) in ls {
             locations.insert(l, a);
         }
-        State {
-            locations,
-            score: 0,
-        }
+        State { locations, depth }
+    }
+
+    // the places of the room of `l` that exist in this burrow, from the hallway down
+    fn rooms(&self, l: Location) -> Vec<Location> {
+        let mut rooms = l.get_rooms_in_order_for_room();
+        rooms.truncate(self.depth);
+        rooms
     }
 
     fn get(&self, l: Location) -> Option<Amphipod> {
@@ -147,7 +154,7 @@ impl State {
     }
 
     fn can_go_on_hallway(&self, from: Location, to: Location) -> bool {
-        let order = vec![AL2, AL1, AB, BC, CD, DR1, DR2];
+        let order = [AL2, AL1, AB, BC, CD, DR1, DR2];
 
         let get_index_from_room_too_hallway_right = |mut t: Location| {
             t = match t {
@@ -205,7 +212,7 @@ impl State {
     }
 
     fn calc_moves(from: Location, to: Location) -> u32 {
-        let order = vec![AL2, AL1, AB, BC, CD, DR1, DR2];
+        let order = [AL2, AL1, AB, BC, CD, DR1, DR2];
 
         let get_index_from_room_too_hallway_right = |mut t: Location| {
             t = match t {
@@ -240,7 +247,7 @@ impl State {
             order.iter().position(|x| *x == to).unwrap()
         };
 
-        let mut hallway_moves = if i1 > i2 { i1 - i2 } else { i2 - i1 };
+        let mut hallway_moves = i1.abs_diff(i2);
         hallway_moves *= 2;
         if matches!(from, AL2 | DR2) || matches!(to, AL2 | DR2) {
             hallway_moves -= 1;
@@ -260,53 +267,16 @@ impl State {
         assert!(map.remove(&l).is_some());
         assert!(map.insert(to, a).is_none());
         State {
-            score: self.score
-                + Self::calc_moves(l, to)
-                    * match a {
-                        A => 1,
-                        B => 10,
-                        C => 100,
-                        D => 1000,
-                    },
             locations: map,
+            depth: self.depth,
         }
     }
 
-    fn solve_rec(
-        &self,
-        cache: &mut HashMap<(u32, Vec<(Location, Amphipod)>), Option<u32>>,
-    ) -> Option<u32> {
-        if self.finished() {
-            return Some(self.score);
-        }
-
-        let key = (
-            self.score,
-            self.locations.iter().map(|x| (*x.0, *x.1)).collect_vec(),
-        );
-        match cache.get(&key) {
-            None => {
-                let mut min = None;
-
-                for (l, a) in &self.locations {
-                    for to in self.moves_for(*l) {
-                        let result = self.move_(*l, *a, to).solve_rec(cache);
-                        if let Some(r) = result {
-                            match min {
-                                None => min = Some(r),
-                                Some(r2) => min = Some(r.min(r2)),
-                            }
-                        }
-                    }
-                }
-
-                cache.insert(key, min);
-
-                min
-            }
-
-            Some(s) => *s,
-        }
+    // the cheapest way to sort the amphipods
+    fn solve(&self) -> Search<State> {
+        let search = dijkstra(&Burrow, self.clone(), |s| s.finished());
+        debug!("{:?}", search.stats);
+        search
     }
 
     fn moves_for(&self, l: Location) -> LocationSet {
@@ -314,7 +284,7 @@ impl State {
         if self.is_finished(l, a) {
             EnumSet::empty()
         } else if l.is_room() {
-            let rooms = l.get_rooms_in_order_for_room();
+            let rooms = self.rooms(l);
             let rooms_before_occupied = rooms
                 .iter()
                 .take_while(|x| **x != l)
@@ -331,7 +301,7 @@ impl State {
             }
         } else {
             // to finish
-            let rooms = a.target_room2().get_rooms_in_order_for_room();
+            let rooms = self.rooms(a.target_room2());
             let target_room = rooms.iter().rev().find(|x| !self.is_occupied(**x)).cloned();
 
             let target_room = match target_room {
@@ -364,7 +334,7 @@ impl State {
 
     fn is_finished(&self, l: Location, a: Amphipod) -> bool {
         Self::is_in_target_room(l, a) && {
-            let rooms = l.get_rooms_in_order_for_room();
+            let rooms = self.rooms(l);
             rooms
                 .iter()
                 .rev()
@@ -392,6 +362,23 @@ impl State {
     }
 }
 
+struct Burrow;
+
+impl Successors for Burrow {
+    type Node = State;
+
+    fn successors(&self, state: &State) -> Vec<(State, u64)> {
+        let mut next = vec![];
+        for (&l, &a) in &state.locations {
+            for to in state.moves_for(l) {
+                let energy = State::calc_moves(l, to) * a.energy();
+                next.push((state.move_(l, a, to), energy as u64));
+            }
+        }
+        next
+    }
+}
+
 impl Display for State {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -421,55 +408,96 @@ impl Display for State {
     }
 }
 
-fn solution1(input: &str) -> Result<String> {
-    let state = State::new(vec![
-        (RA1, D),
-        (RA2, C),
-        (RB1, B),
-        (RB2, A),
-        (RC1, D),
-        (RC2, A),
-        (RD1, B),
-        (RD2, C),
-    ]);
-
-    let mut map = Default::default();
-    Ok(format!("{}", state.solve_rec(&mut map).unwrap()))
-}
+pub struct Day23;
+
+impl Day for Day23 {
+    type Input = Vec<(Location, Amphipod)>;
+
+    fn parse(input: &str) -> Result<Vec<(Location, Amphipod)>> {
+        let rooms = [
+            [RA1, RB1, RC1, RD1],
+            [RA2, RB2, RC2, RD2],
+            [RA3, RB3, RC3, RD3],
+            [RA4, RB4, RC4, RD4],
+        ];
+
+        let mut ls = vec![];
+        let mut row = 0;
+        for (i, line) in input.lines().enumerate() {
+            let amphipods = line
+                .char_indices()
+                .filter(|(_, c)| !"#. ".contains(*c))
+                .collect_vec();
+            if amphipods.is_empty() {
+                continue;
+            }
+            if row == rooms.len() || amphipods.len() != 4 {
+                return Err(ParseError::whole_line(i + 1, line, "a row of 4 rooms").into());
+            }
+
+            for (col, &(j, c)) in amphipods.iter().enumerate() {
+                let a = match c {
+                    'A' => A,
+                    'B' => B,
+                    'C' => C,
+                    'D' => D,
+                    _ => return Err(ParseError::new(i + 1, line, j..j + 1, "an amphipod").into()),
+                };
+                ls.push((rooms[row][col], a));
+            }
+            row += 1;
+        }
+
+        Ok(ls)
+    }
+
+    fn part1(ls: &Vec<(Location, Amphipod)>) -> Result<String> {
+        let state = State::new(ls.clone());
+
+        Ok(format!("{}", state.solve().cost().unwrap()))
+    }
+
+    fn part2(ls: &Vec<(Location, Amphipod)>) -> Result<String> {
+        let unfolded = vec![
+            (RA2, D),
+            (RB2, C),
+            (RC2, B),
+            (RD2, A),
+            (RA3, D),
+            (RB3, B),
+            (RC3, A),
+            (RD3, C),
+        ];
+
+        let state = State::new(
+            ls.iter()
+                .map(|&(l, a)| match l {
+                    RA2 => (RA4, a),
+                    RB2 => (RB4, a),
+                    RC2 => (RC4, a),
+                    RD2 => (RD4, a),
+                    _ => (l, a),
+                })
+                .chain(unfolded)
+                .collect_vec(),
+        );
 
-fn solution2(input: &str) -> Result<String> {
-    let state = State::new(vec![
-        (RA1, D),
-        (RA2, D),
-        (RA3, D),
-        (RA4, C),
-        (RB1, B),
-        (RB2, C),
-        (RB3, B),
-        (RB4, A),
-        (RC1, D),
-        (RC2, B),
-        (RC3, A),
-        (RC4, A),
-        (RD1, B),
-        (RD2, A),
-        (RD3, C),
-        (RD4, C),
-    ]);
-
-    let mut map = Default::default();
-    Ok(format!("{}", state.solve_rec(&mut map).unwrap()))
+        Ok(format!("{}", state.solve().cost().unwrap()))
+    }
 }
 
+#[cfg(test)]
 mod tests {
-    use crate::day23::Amphipod::*;
-    use crate::day23::Location::*;
-    use crate::day23::{solution1, solution2, Amphipod, HashMap, Location, State};
+    use crate::assert_snapshot;
     use crate::run_solution;
-    use indoc::indoc;
+    use crate::y2021::day23::Amphipod::*;
+    use crate::y2021::day23::Location::*;
+    use crate::y2021::day23::{Day23, State};
+    use crate::Day;
+
     use itertools::Itertools;
 
-    const INPUT: &'static str = "day1.txt";
+    const INPUT: &str = "2021/day23.txt";
 
     // PART 1
 
@@ -520,6 +548,18 @@ mod tests {
         assert!(state.can_go_on_hallway(RC1, DR2));
     }
 
+    #[test]
+    fn test_parse() {
+        let diagram = "#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########
+";
+        let state = State::new(Day23::parse(diagram).unwrap());
+        assert_eq!(diagram, format!("{}", state));
+    }
+
     #[test]
     fn test_part1() {
         let state = State::new(vec![
@@ -533,15 +573,7 @@ mod tests {
             (RD2, A),
         ]);
 
-        assert_eq!(
-            "#############
-#...........#
-###B#C#B#D###
-  #A#D#C#A#
-  #########
-",
-            format!("{}", state)
-        );
+        assert_snapshot!(state);
 
         assert_eq!("EnumSet()", format!("{:?}", state.moves_for(RA2)));
         assert_eq!(
@@ -564,16 +596,15 @@ mod tests {
             format!("{:?}", state.moves_for(RD1).iter().collect_vec())
         );
 
-        let mut map: HashMap<(u32, Vec<(Location, Amphipod)>), Option<u32>> = Default::default();
-        let option = state.solve_rec(&mut map);
-        let min = map.values().filter_map(|x| *x).min().unwrap();
-        assert_eq!(12521, min);
-        assert_eq!(Some(12521), option)
+        let path = state.solve().path.unwrap();
+        assert_eq!(12521, path.cost);
+        assert_eq!(&state, path.nodes.first().unwrap());
+        assert!(path.nodes.last().unwrap().finished());
     }
 
     #[test]
     fn run_solution1() {
-        run_solution(INPUT, solution1).unwrap()
+        run_solution(INPUT, Day23::solution1).unwrap()
     }
 
     // PART 2
@@ -598,12 +629,11 @@ mod tests {
             (RD3, C),
             (RD4, A),
         ]);
-        let mut map: HashMap<(u32, Vec<(Location, Amphipod)>), Option<u32>> = Default::default();
-        assert_eq!(Some(44169), state.solve_rec(&mut map));
+        assert_eq!(Some(44169), state.solve().cost());
     }
 
     #[test]
     fn run_solution2() {
-        run_solution(INPUT, solution2).unwrap()
+        run_solution(INPUT, Day23::solution2).unwrap()
     }
 }
@@ -1,12 +1,8 @@
-use crate::day24::Instr::Inp;
-use crate::util::{parse_lines, parse_lines_regex};
+use crate::util::{parse_lines_regex, ParseError};
+use crate::{debug, info, Day};
 use anyhow::Result;
-use itertools::{izip, Itertools};
-use regex::internal::Inst;
-use regex::Regex;
-use std::fmt::{format, Display, Formatter};
+use std::fmt::{Display, Formatter};
 
-type HashMap<T, V> = rustc_hash::FxHashMap<T, V>;
 type HashSet<T> = rustc_hash::FxHashSet<T>;
 
 type Word = i64;
@@ -48,10 +44,10 @@ impl<'a> State<'a> {
         }
     }
 
-    fn exec_until_end(&mut self, instr: &Vec<Instr>) -> Option<Word> {
+    fn exec_until_end(&mut self, instr: &[Instr]) -> Option<Word> {
         for x in instr.iter() {
-            x.clone().exec(self);
-            if let Some(e) = &self.err {
+            (*x).exec(self);
+            if self.err.is_some() {
                 return None;
             }
         }
@@ -79,13 +75,13 @@ pub enum Reg {
 }
 
 #[derive(Debug, Clone, Copy)]
-enum Val {
+pub enum Val {
     Static(Word),
     Reg(Reg),
 }
 
 #[derive(Debug, Clone, Copy)]
-enum Instr {
+pub enum Instr {
     Inp(Val),
     Add(Val, Val),
     Mul(Val, Val),
@@ -122,7 +118,7 @@ impl Display for Expr {
 }
 
 impl Expr {
-    fn simplify(&self) -> Expr {
+    pub fn simplify(&self) -> Expr {
         match self {
             Expr::Add(x, y) => {
                 let x = x.simplify();
@@ -192,10 +188,10 @@ impl Expr {
                 let y = y.simplify();
 
                 match (&x, &y) {
-                    (Expr::Val(x), Expr::Inp(_)) | (Expr::Inp(_), Expr::Val(x)) => {
-                        if *x <= 0 || *x > 9 {
-                            return Expr::Val(0);
-                        }
+                    (Expr::Val(x), Expr::Inp(_)) | (Expr::Inp(_), Expr::Val(x))
+                        if *x <= 0 || *x > 9 =>
+                    {
+                        return Expr::Val(0);
                     }
                     _ => {}
                 }
@@ -273,6 +269,12 @@ pub struct BackAnalyzer {
     pub inp_i: usize,
 }
 
+impl Default for BackAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl BackAnalyzer {
     pub fn new() -> BackAnalyzer {
         BackAnalyzer {
@@ -293,9 +295,9 @@ impl BackAnalyzer {
         }
     }
 
-    fn exec(&mut self, instr: &Vec<Instr>) {
+    pub fn exec(&mut self, instr: &[Instr]) {
         for x in instr.iter() {
-            self.add(x.clone());
+            self.add(*x);
         }
     }
     fn replace(&mut self, reg: Reg, expr: Expr) {
@@ -334,7 +336,7 @@ impl BackAnalyzer {
         }
     }
 
-    fn add(&mut self, instr: Instr) {
+    pub fn add(&mut self, instr: Instr) {
         match instr {
             Instr::Inp(Val::Reg(x)) => {
                 self.replace(x, Expr::Inp(self.inp_i));
@@ -365,36 +367,115 @@ impl BackAnalyzer {
     }
 }
 
-fn parse(s: &str) -> Vec<Instr> {
-    s.trim()
-        .lines()
+pub fn parse(s: &str) -> Result<Vec<Instr>, ParseError> {
+    let xs = parse_lines_regex(
+        s.trim(),
+        r"^\s*(inp|add|mul|div|mod|eql) ([wxyz])(?: (\S+))?\s*$",
+    )?;
+
+    xs.iter()
         .map(|l| {
-            fn parse_val(s: &str) -> Val {
-                match s {
+            let parse_val = |i: usize| -> Result<Val, ParseError> {
+                Ok(match l[i].as_str() {
                     "w" => Val::Reg(Reg::W),
                     "x" => Val::Reg(Reg::X),
                     "y" => Val::Reg(Reg::Y),
                     "z" => Val::Reg(Reg::Z),
-                    _ => Val::Static(s.parse().unwrap()),
+                    _ => Val::Static(l.parse(i)?),
+                })
+            };
+
+            let a = parse_val(2)?;
+            if l[1] == "inp" {
+                return match l[3].is_empty() {
+                    true => Ok(Instr::Inp(a)),
+                    false => Err(l.error(3, "end of line")),
+                };
+            }
+            if l[3].is_empty() {
+                let end = l.source.trim_end().len();
+                return Err(ParseError::new(
+                    l.line,
+                    &l.source,
+                    end..end,
+                    "a second operand",
+                ));
+            }
+
+            let b = parse_val(3)?;
+            Ok(match l[1].as_str() {
+                "add" => Instr::Add(a, b),
+                "mul" => Instr::Mul(a, b),
+                "div" => Instr::Div(a, b),
+                "mod" => Instr::Mod(a, b),
+                _ => Instr::Eql(a, b),
+            })
+        })
+        .collect()
+}
+
+pub struct Day24;
+
+impl Day for Day24 {
+    type Input = Vec<Vec<Instr>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<Instr>>> {
+        let mut sub_programs: Vec<Vec<Instr>> = vec![];
+        for instr in parse(input)? {
+            if let Instr::Inp(_) = instr {
+                sub_programs.push(vec![]);
+            }
+            match sub_programs.last_mut() {
+                None => {
+                    let line = input.lines().next().unwrap_or("");
+                    return Err(ParseError::whole_line(1, line, "an inp instruction").into());
                 }
+                Some(sub_program) => sub_program.push(instr),
             }
+        }
+
+        Ok(sub_programs)
+    }
 
-            let s = l.trim().split(' ').collect_vec();
-            match s[0] {
-                "inp" => Instr::Inp(parse_val(s[1])),
-                "add" => Instr::Add(parse_val(s[1]), parse_val(s[2])),
-                "mul" => Instr::Mul(parse_val(s[1]), parse_val(s[2])),
-                "div" => Instr::Div(parse_val(s[1]), parse_val(s[2])),
-                "mod" => Instr::Mod(parse_val(s[1]), parse_val(s[2])),
-                "eql" => Instr::Eql(parse_val(s[1]), parse_val(s[2])),
-                _ => panic!("Unexepected instruction: {}", l),
+    fn part1(sub_programs: &Vec<Vec<Instr>>) -> Result<String> {
+        let mut target_z_states: HashSet<Word> = Default::default();
+        target_z_states.insert(0);
+        //(11..=19).for_each(|x|{target_z_states.insert(x);});
+        //let mut valid_states: Vec<HashSet<(Word, Word, Word)>> = (0..14).map(|x| Default::default()).collect_vec();
+
+        for x in sub_programs.iter() {
+            let mut valid_z_states: HashSet<Word> = Default::default();
+
+            for input in 1..=9 {
+                for z in target_z_states.iter() {
+                    let inp = vec![input];
+                    let mut state = State::new(&inp);
+                    state.z = *z;
+                    match state.exec_until_end(x) {
+                        None => {}
+                        Some(z_out) => {
+                            valid_z_states.insert(z_out);
+                            //valid_states[i].insert((z_out, *z, input));
+                        }
+                    };
+                }
             }
-        })
-        .collect_vec()
+
+            target_z_states = valid_z_states;
+        }
+
+        info!("States len:{}", target_z_states.len());
+
+        Ok("??".to_string())
+    }
+
+    fn part2(_sub_programs: &Vec<Vec<Instr>>) -> Result<String> {
+        Ok("?".to_string())
+    }
 }
 
-fn solution1(input: &str) -> Result<String> {
-    let sub_programs = vec![
+pub fn day24() {
+    let instr = parse(
         "inp w
 mul x 0
 add x z
@@ -413,8 +494,7 @@ add y w
 add y 10
 mul y x
 add z y
-",
-        "inp w
+inp w
 mul x 0
 add x z
 mod x 26
@@ -432,8 +512,7 @@ add y w
 add y 16
 mul y x
 add z y
-",
-        "inp w
+inp w
 mul x 0
 add x z
 mod x 26
@@ -451,8 +530,7 @@ add y w
 add y 0
 mul y x
 add z y
-",
-        "inp w
+inp w
 mul x 0
 add x z
 mod x 26
@@ -470,8 +548,7 @@ add y w
 add y 13
 mul y x
 add z y
-",
-        "inp w
+inp w
 mul x 0
 add x z
 mod x 26
@@ -489,8 +566,7 @@ add y w
 add y 7
 mul y x
 add z y
-",
-        "inp w
+inp w
 mul x 0
 add x z
 mod x 26
@@ -508,8 +584,7 @@ add y w
 add y 11
 mul y x
 add z y
-",
-        "inp w
+inp w
 mul x 0
 add x z
 mod x 26
@@ -527,8 +602,7 @@ add y w
 add y 11
 mul y x
 add z y
-",
-        "inp w
+inp w
 mul x 0
 add x z
 mod x 26
@@ -546,8 +620,7 @@ add y w
 add y 10
 mul y x
 add z y
-",
-        "inp w
+inp w
 mul x 0
 add x z
 mod x 26
@@ -565,8 +638,7 @@ add y w
 add y 16
 mul y x
 add z y
-",
-        "inp w
+inp w
 mul x 0
 add x z
 mod x 26
@@ -584,8 +656,7 @@ add y w
 add y 8
 mul y x
 add z y
-",
-        "inp w
+inp w
 mul x 0
 add x z
 mod x 26
@@ -603,8 +674,7 @@ add y w
 add y 15
 mul y x
 add z y
-",
-        "inp w
+inp w
 mul x 0
 add x z
 mod x 26
@@ -622,8 +692,7 @@ add y w
 add y 2
 mul y x
 add z y
-",
-        "inp w
+inp w
 mul x 0
 add x z
 mod x 26
@@ -641,8 +710,7 @@ add y w
 add y 5
 mul y x
 add z y
-",
-        "inp w
+inp w
 mul x 0
 add x z
 mod x 26
@@ -660,109 +728,91 @@ add y w
 add y 10
 mul y x
 add z y",
-    ];
-
-    let mut target_z_states: HashSet<Word> = Default::default();
-    target_z_states.insert(0);
-    //(11..=19).for_each(|x|{target_z_states.insert(x);});
-    //let mut valid_states: Vec<HashSet<(Word, Word, Word)>> = (0..14).map(|x| Default::default()).collect_vec();
-
-    for (i, x) in sub_programs.iter().enumerate() {
-        let mut valid_z_states: HashSet<Word> = Default::default();
-
-        for input in 1..=9 {
-            for z in target_z_states.iter() {
-                let inp = vec![input];
-                let mut state = State::new(&inp);
-                state.z = *z;
-                match state.exec_until_end(&parse(*x)) {
-                    None => {}
-                    Some(z_out) => {
-                        valid_z_states.insert(z_out);
-                        //valid_states[i].insert((z_out, *z, input));
-                    }
-                };
-            }
-        }
-
-        target_z_states = valid_z_states;
-    }
+    )
+    .unwrap();
 
-    println!("States len:{}", target_z_states.len());
-
-    Ok(format!("{}", "??"))
-}
-
-fn find_highest_reachable_number(
-    x: &Vec<HashMap<Word, HashMap<Word, Word>>>,
-    i: usize,
-    z: Word,
-) -> Option<String> {
-    if i == x.len() {
-        //assert_eq!(z, 0);
-        return Some("".into());
+    let mut ba = BackAnalyzer::new();
+    for (i, x) in instr.iter().enumerate() {
+        ba.add(*x);
+        debug!("i={}", i);
     }
 
-    (0..=9)
-        .rev()
-        .filter_map(|num| match x[i].get(&num) {
-            None => None,
-            Some(zs) => match zs.get(&z) {
-                None => None,
-                Some(next_z) => find_highest_reachable_number(x, i + 1, *next_z)
-                    .map(|num2| num.to_string() + &num2),
-            },
-        })
-        .next()
-}
-
-fn solution2(input: &str) -> Result<String> {
-    Ok(format!("{}", "?"))
+    assert_eq!("((inp_0*3)==inp_1)", format!("{}", ba.z));
 }
 
+#[cfg(test)]
 mod tests {
-    use crate::day24::{parse, solution1, solution2, BackAnalyzer, Expr, State};
+    use crate::assert_snapshot;
+    use crate::debug;
     use crate::run_solution;
+    use crate::util::ParseError;
+    use crate::y2021::day24::{parse, BackAnalyzer, Day24, Expr, State};
+    use crate::Day;
     use indoc::indoc;
 
-    const INPUT: &'static str = "day24.txt";
+    const INPUT: &str = "2021/day24.txt";
 
     // PART 1
 
     #[test]
     fn test_ins() {
-        let v = vec![3];
         assert_eq!(
             Some(-3),
-            State::new(&vec![3]).exec_until_end(&parse(
-                "inp z
+            State::new(&vec![3]).exec_until_end(
+                &parse(
+                    "inp z
         mul z -1"
-            ))
+                )
+                .unwrap()
+            )
         );
 
         let v = vec![3, 9];
         let mut state = State::new(&v);
-        let s = state.exec_until_end(&parse(
-            "inp z
+        let s = state.exec_until_end(
+            &parse(
+                "inp z
 inp x
 mul z 3
 eql z x",
-        ));
+            )
+            .unwrap(),
+        );
         assert!(s.is_some());
         assert_eq!(1, state.z);
 
         let v = vec![3, 10];
         let mut state = State::new(&v);
-        let s = state.exec_until_end(&parse(
-            "inp z
+        let s = state.exec_until_end(
+            &parse(
+                "inp z
 inp x
 mul z 3
 eql z x",
-        ));
+            )
+            .unwrap(),
+        );
         assert!(s.is_some());
         assert_eq!(0, state.z);
     }
 
+    #[test]
+    fn test_parse_error() {
+        let e = parse("inp w\nadd x\nmul y 2").unwrap_err();
+        assert_eq!((2, 5..5), (e.line, e.columns));
+        assert_eq!("a second operand", e.expected);
+
+        let e = parse("inp w\nfoo x 1").unwrap_err();
+        assert_eq!((2, 0..7), (e.line, e.columns));
+
+        let e = parse("inp w\nadd x 1x").unwrap_err();
+        assert_eq!((2, 6..8), (e.line, e.columns));
+
+        let e = Day24::parse("add x 1\ninp w").unwrap_err();
+        let e = e.downcast::<ParseError>().unwrap();
+        assert_eq!((1, "an inp instruction"), (e.line, e.expected.as_str()));
+    }
+
     #[test]
     fn test_back() {
         let instr = parse(
@@ -770,13 +820,14 @@ eql z x",
 inp x
 mul z 3
 eql z x",
-        );
+        )
+        .unwrap();
         //z*3 == x
         // z = ((inp_1)*3) == (inp_0)
         let mut ba = BackAnalyzer::new();
         for x in instr.iter() {
-            ba.add(x.clone());
-            println!("z={}", ba.z);
+            ba.add(*x);
+            debug!("z={}", ba.z);
         }
 
         assert_eq!("((inp_0*3)==inp_1)", format!("{}", ba.z));
@@ -803,13 +854,17 @@ add y w
 add y 16
 mul y x
 add z y",
-        );
+        )
+        .unwrap();
 
         let mut ba = BackAnalyzer::new_with_deps();
         ba.exec(&instr);
-        println!("x={}\n\ny={}\n\nz={}\n\nw={}", ba.x, ba.y, ba.z, ba.w);
+        assert_snapshot!(format!(
+            "x={}\n\ny={}\n\nz={}\n\nw={}",
+            ba.x, ba.y, ba.z, ba.w
+        ));
 
-        assert_eq!("((inp_0*3)==inp_1)", format!("{}", ba.z));
+        assert_eq!("inp_0", format!("{}", ba.w));
     }
 
     #[test]
@@ -825,290 +880,23 @@ add z y",
 
     #[test]
     fn test_part1() {
-        assert_eq!("??", solution1(indoc!("")).unwrap());
+        assert_eq!("??", Day24::solution1(indoc!("")).unwrap());
     }
 
     #[test]
     fn run_solution1() {
-        run_solution(INPUT, solution1).unwrap()
+        run_solution(INPUT, Day24::solution1).unwrap()
     }
 
     // PART 2
 
     #[test]
     fn test_part2() {
-        assert_eq!("??", solution2(indoc!("")).unwrap());
+        assert_eq!("?", Day24::solution2(indoc!("")).unwrap());
     }
 
     #[test]
     fn run_solution2() {
-        run_solution(INPUT, solution2).unwrap()
-    }
-}
-
-pub fn day24() {
-    let instr = parse(
-        "inp w
-mul x 0
-add x z
-mod x 26
-div z 1
-add x 13
-eql x w
-eql x 0
-mul y 0
-add y 25
-mul y x
-add y 1
-mul z y
-mul y 0
-add y w
-add y 10
-mul y x
-add z y
-inp w
-mul x 0
-add x z
-mod x 26
-div z 1
-add x 11
-eql x w
-eql x 0
-mul y 0
-add y 25
-mul y x
-add y 1
-mul z y
-mul y 0
-add y w
-add y 16
-mul y x
-add z y
-inp w
-mul x 0
-add x z
-mod x 26
-div z 1
-add x 11
-eql x w
-eql x 0
-mul y 0
-add y 25
-mul y x
-add y 1
-mul z y
-mul y 0
-add y w
-add y 0
-mul y x
-add z y
-inp w
-mul x 0
-add x z
-mod x 26
-div z 1
-add x 10
-eql x w
-eql x 0
-mul y 0
-add y 25
-mul y x
-add y 1
-mul z y
-mul y 0
-add y w
-add y 13
-mul y x
-add z y
-inp w
-mul x 0
-add x z
-mod x 26
-div z 26
-add x -14
-eql x w
-eql x 0
-mul y 0
-add y 25
-mul y x
-add y 1
-mul z y
-mul y 0
-add y w
-add y 7
-mul y x
-add z y
-inp w
-mul x 0
-add x z
-mod x 26
-div z 26
-add x -4
-eql x w
-eql x 0
-mul y 0
-add y 25
-mul y x
-add y 1
-mul z y
-mul y 0
-add y w
-add y 11
-mul y x
-add z y
-inp w
-mul x 0
-add x z
-mod x 26
-div z 1
-add x 11
-eql x w
-eql x 0
-mul y 0
-add y 25
-mul y x
-add y 1
-mul z y
-mul y 0
-add y w
-add y 11
-mul y x
-add z y
-inp w
-mul x 0
-add x z
-mod x 26
-div z 26
-add x -3
-eql x w
-eql x 0
-mul y 0
-add y 25
-mul y x
-add y 1
-mul z y
-mul y 0
-add y w
-add y 10
-mul y x
-add z y
-inp w
-mul x 0
-add x z
-mod x 26
-div z 1
-add x 12
-eql x w
-eql x 0
-mul y 0
-add y 25
-mul y x
-add y 1
-mul z y
-mul y 0
-add y w
-add y 16
-mul y x
-add z y
-inp w
-mul x 0
-add x z
-mod x 26
-div z 26
-add x -12
-eql x w
-eql x 0
-mul y 0
-add y 25
-mul y x
-add y 1
-mul z y
-mul y 0
-add y w
-add y 8
-mul y x
-add z y
-inp w
-mul x 0
-add x z
-mod x 26
-div z 1
-add x 13
-eql x w
-eql x 0
-mul y 0
-add y 25
-mul y x
-add y 1
-mul z y
-mul y 0
-add y w
-add y 15
-mul y x
-add z y
-inp w
-mul x 0
-add x z
-mod x 26
-div z 26
-add x -12
-eql x w
-eql x 0
-mul y 0
-add y 25
-mul y x
-add y 1
-mul z y
-mul y 0
-add y w
-add y 2
-mul y x
-add z y
-inp w
-mul x 0
-add x z
-mod x 26
-div z 26
-add x -15
-eql x w
-eql x 0
-mul y 0
-add y 25
-mul y x
-add y 1
-mul z y
-mul y 0
-add y w
-add y 5
-mul y x
-add z y
-inp w
-mul x 0
-add x z
-mod x 26
-div z 26
-add x -12
-eql x w
-eql x 0
-mul y 0
-add y 25
-mul y x
-add y 1
-mul z y
-mul y 0
-add y w
-add y 10
-mul y x
-add z y",
-    );
-
-    let mut ba = BackAnalyzer::new();
-    let mut i = 0;
-    for x in instr.iter() {
-        ba.add(x.clone());
-        println!("i={}", i);
-        i += 1;
+        run_solution(INPUT, Day24::solution2).unwrap()
     }
-
-    assert_eq!("((inp_0*3)==inp_1)", format!("{}", ba.z));
 }
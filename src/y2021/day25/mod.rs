@@ -0,0 +1,200 @@
+use crate::util::grid::Grid;
+use crate::util::sim::{Cycle, Sim, Simulation};
+use crate::viz::{Cell, Frame, Frames};
+use crate::Day;
+use anyhow::{bail, Result};
+use std::fmt::{Display, Formatter};
+
+#[derive(Clone, Hash, PartialEq, Eq)]
+struct SeaFloor {
+    cells: Grid<char>,
+}
+
+impl Display for SeaFloor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.cells)
+    }
+}
+
+impl SeaFloor {
+    pub fn new(cells: Grid<char>) -> Self {
+        SeaFloor { cells }
+    }
+
+    // moves every cucumber of the herd `c` that can move in direction `delta`
+    fn move_herd(&mut self, c: char, delta: (i64, i64)) {
+        let mut new = self.cells.clone();
+
+        for (pos, &x) in self.cells.iter() {
+            let target = self.cells.offset_wrapping(pos, delta);
+            if x == c && is_empty(self.cells[target]) {
+                new[pos] = '.';
+                new[target] = c;
+            }
+        }
+
+        self.cells = new;
+    }
+}
+
+impl Simulation for SeaFloor {
+    fn step(&mut self) {
+        self.move_herd('>', (1, 0));
+        self.move_herd('v', (0, 1));
+    }
+}
+
+fn is_east(c: char) -> bool {
+    c == '>'
+}
+
+fn is_south(c: char) -> bool {
+    c == 'v'
+}
+
+fn is_empty(c: char) -> bool {
+    c == '.'
+}
+
+// the herds after every step until they stop moving
+pub fn frames(input: &str) -> Result<Frames> {
+    let mut sim = Sim::new(SeaFloor::new(Day25::parse(input)?));
+    let mut previous: Option<SeaFloor> = None;
+    Ok(Box::new(std::iter::from_fn(move || {
+        if let Some(prev) = &previous {
+            sim.step();
+            if prev == sim.state() {
+                return None;
+            }
+        }
+        previous = Some(sim.state().clone());
+        Some(Frame {
+            caption: format!("step {}", sim.steps()),
+            cells: sim.state().cells.map(|&c| match c {
+                '>' => Cell::heat(c, 3),
+                'v' => Cell::heat(c, 8),
+                _ => Cell::plain(c),
+            }),
+        })
+    })))
+}
+
+// herds that neither stop nor repeat within as many steps are given up on
+const MAX_STEPS: usize = 10_000;
+
+pub struct Day25;
+
+impl Day for Day25 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Grid<char>> {
+        let is_cell = |c: char| is_east(c) || is_south(c) || is_empty(c);
+        Ok(Grid::parse(input, "'>', 'v' or '.'", |c| {
+            Some(c).filter(|&c| is_cell(c))
+        })?)
+    }
+
+    fn part1(xs: &Grid<char>) -> Result<String> {
+        let sim = Sim::new(SeaFloor::new(xs.clone()));
+        match sim.find_cycle(MAX_STEPS) {
+            // nothing changes after `start` steps, so step `start + 1` is the first in which
+            // nothing moves
+            Some(Cycle { start, len: 1 }) => Ok(format!("{}", start + 1)),
+            // e.g. a row with a single gap, which moves around forever
+            Some(Cycle { start, len }) => bail!(
+                "The sea cucumbers never stop, from step {} on they repeat every {} steps",
+                start,
+                len
+            ),
+            None => bail!("The sea cucumbers still move after {} steps", MAX_STEPS),
+        }
+    }
+
+    fn part2(_xs: &Grid<char>) -> Result<String> {
+        Ok("?".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_snapshot;
+    use crate::run_solution;
+    use crate::util::grid::Grid;
+    use crate::util::sim::Simulation;
+    use crate::y2021::day25::{Day25, SeaFloor};
+    use crate::Day;
+    use indoc::indoc;
+    use itertools::Itertools;
+
+    const INPUT: &str = "2021/day25.txt";
+
+    // PART 1
+
+    #[test]
+    fn test_1() {
+        let cells = Grid::from_rows(
+            "v...>>.vv>
+.vv>>.vv..
+>>.>v>...v
+>>v>>.>.v.
+v>v.vv.v..
+>.>>..v...
+.vv..>.>v.
+v.v..>>v.v
+....v..v.>"
+                .lines()
+                .map(|c| c.chars().collect_vec())
+                .collect_vec(),
+        );
+        let mut floor = SeaFloor::new(cells);
+
+        floor.step();
+        assert_snapshot!(floor);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            "58",
+            Day25::solution1(indoc!(
+                "v...>>.vv>
+.vv>>.vv..
+>>.>v>...v
+>>v>>.>.v.
+v>v.vv.v..
+>.>>..v...
+.vv..>.>v.
+v.v..>>v.v
+....v..v.>"
+            ))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_part1_never_stops() {
+        let e = Day25::solution1(">.>>").unwrap_err();
+        assert_eq!(
+            "The sea cucumbers never stop, from step 0 on they repeat every 4 steps",
+            e.to_string()
+        );
+    }
+
+    #[test]
+    fn run_solution1() {
+        run_solution(INPUT, Day25::solution1).unwrap()
+    }
+
+    // PART 2
+
+    #[test]
+    fn test_part2() {
+        // there is no second puzzle on the last day
+        assert_eq!("?", Day25::solution2(indoc!("")).unwrap());
+    }
+
+    #[test]
+    fn run_solution2() {
+        run_solution(INPUT, Day25::solution2).unwrap()
+    }
+}
@@ -0,0 +1,136 @@
+use crate::util::{parse_numbers, sections, ParseError};
+use crate::Day;
+use anyhow::Result;
+use itertools::Itertools;
+use std::collections::HashSet;
+
+#[derive(Clone)]
+pub struct Bingo {
+    nums: Vec<Vec<i32>>,
+    drawn: HashSet<i32>,
+}
+
+impl Bingo {
+    pub fn new(nums: Vec<Vec<i32>>) -> Self {
+        Bingo {
+            nums,
+            drawn: HashSet::new(),
+        }
+    }
+
+    pub fn complete(&self) -> bool {
+        (0..5).any(|y| (0..5).all(|x| self.drawn.contains(&self.nums[x][y])))
+            || (0..5).any(|y| (0..5).all(|x| self.drawn.contains(&self.nums[y][x])))
+    }
+
+    pub fn add(&mut self, n: i32) -> Option<i32> {
+        self.drawn.insert(n);
+        if self.complete() {
+            let sum: i32 = self
+                .nums
+                .iter()
+                .flat_map(|xs| xs.iter().filter(|x| !self.drawn.contains(*x)))
+                .sum();
+            Some(sum * n)
+        } else {
+            None
+        }
+    }
+}
+
+fn parse_board(s: &str) -> Result<Bingo, ParseError> {
+    let rows: Vec<Vec<i32>> = s
+        .lines()
+        .enumerate()
+        .map(|(i, l)| match parse_numbers::<i32>(i + 1, l)? {
+            row if row.len() == 5 => Ok(row),
+            _ => Err(ParseError::whole_line(i + 1, l, "a row of 5 numbers")),
+        })
+        .try_collect()?;
+
+    match rows.len() {
+        5 => Ok(Bingo::new(rows)),
+        n => Err(ParseError::new(n + 1, "", 0..0, "a board of 5 rows")),
+    }
+}
+
+pub struct Day4;
+
+impl Day for Day4 {
+    type Input = (Vec<i32>, Vec<Bingo>);
+
+    fn parse(input: &str) -> Result<(Vec<i32>, Vec<Bingo>)> {
+        let sections = sections(input);
+        let numbers = match sections.first() {
+            Some(s) => s.parse(|s| parse_numbers::<i32>(1, s))?,
+            None => return Err(ParseError::new(1, "", 0..0, "the drawn numbers").into()),
+        };
+
+        let bingos: Vec<Bingo> = sections[1..]
+            .iter()
+            .map(|s| s.parse(parse_board))
+            .try_collect()?;
+
+        Ok((numbers, bingos))
+    }
+
+    fn part1((numbers, bingos): &(Vec<i32>, Vec<Bingo>)) -> Result<String> {
+        let mut bingos = bingos.clone();
+
+        loop {
+            for x in numbers {
+                for b in bingos.iter_mut() {
+                    match &b.add(*x) {
+                        None => {}
+                        Some(score) => return Ok(format!("{}", *score)),
+                    }
+                }
+            }
+        }
+    }
+
+    fn part2((numbers, bingos): &(Vec<i32>, Vec<Bingo>)) -> Result<String> {
+        let mut bingos = bingos.clone();
+        let mut winners: HashSet<usize> = HashSet::new();
+        let lenn = bingos.len();
+
+        loop {
+            for x in numbers {
+                for (i, b) in bingos.iter_mut().enumerate() {
+                    match &b.add(*x) {
+                        None => {}
+                        Some(score) => {
+                            winners.insert(i);
+                            if winners.len() == lenn {
+                                return Ok(format!("{}", *score));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::run_solution;
+
+    use crate::y2021::day4::Day4;
+    use crate::Day;
+
+    const INPUT: &str = "2021/day4.txt";
+
+    // PART 1
+
+    #[test]
+    fn run_solution1() {
+        run_solution(INPUT, Day4::solution1).unwrap()
+    }
+
+    // PART 2
+    #[test]
+    fn run_solution2() {
+        run_solution(INPUT, Day4::solution2).unwrap()
+    }
+}
@@ -0,0 +1,179 @@
+use crate::util::geom::{Rect, Vec2};
+use crate::util::parse_regex;
+use crate::{debug, trace, Day};
+use anyhow::Result;
+use itertools::Itertools;
+use std::collections::HashMap;
+
+#[derive(Debug, Copy, Clone)]
+pub struct Line(Vec2<i32>, Vec2<i32>);
+
+impl Line {
+    pub fn is_straight(&self) -> bool {
+        self.0 .0 == self.1 .0 || self.0 .1 == self.1 .1
+    }
+    // the points of a straight line, ordered by x, then y
+    pub fn iter(&self) -> impl Iterator<Item = Vec2<i32>> {
+        Rect::inclusive(self.0, self.1).points()
+    }
+    // the points from start to end of a straight or diagonal line
+    pub fn walk(&self) -> impl Iterator<Item = Vec2<i32>> {
+        let (start, dir) = (self.0, (self.1 - self.0).signum());
+        (0..=self.0.chebyshev(self.1)).map(move |i| start + dir * i)
+    }
+}
+
+pub struct Day5;
+
+impl Day for Day5 {
+    type Input = Vec<Line>;
+
+    fn parse(input: &str) -> Result<Vec<Line>> {
+        let xs = parse_regex::<(i32, i32, i32, i32)>(input, "^(.+?),(.+?) -> (.+?),(.+?)$")?;
+
+        Ok(xs
+            .into_iter()
+            .map(|(x1, y1, x2, y2)| Line(Vec2(x1, y1), Vec2(x2, y2)))
+            .collect())
+    }
+
+    fn part1(lines: &Vec<Line>) -> Result<String> {
+        let ys = lines
+            .iter()
+            .inspect(|l| trace!("{:?}", l))
+            .copied()
+            .filter(|l| l.is_straight())
+            .collect::<Vec<Line>>();
+
+        let mut field: HashMap<Vec2<i32>, i32> = HashMap::new();
+
+        for line in ys {
+            for point in line.iter() {
+                match field.get(&point).cloned() {
+                    None => field.insert(point, 1),
+                    Some(r) => field.insert(point, r + 1),
+                };
+            }
+        }
+
+        let i = field.values().filter(|x| **x >= 2).count();
+
+        Ok(format!("{}", i))
+    }
+
+    fn part2(lines: &Vec<Line>) -> Result<String> {
+        let mut field: HashMap<Vec2<i32>, i32> = HashMap::new();
+
+        for line in lines {
+            line.walk().for_each(|point| {
+                match field.get(&point).cloned() {
+                    None => field.insert(point, 1),
+                    Some(r) => field.insert(point, r + 1),
+                };
+            });
+
+            //dbg!(field.keys());
+        }
+
+        debug!(
+            "\n{}",
+            (0..12)
+                .map(|y| {
+                    (0..12)
+                        .map(|x| field.get(&Vec2(x, y)).map_or(".".into(), |x| x.to_string()))
+                        .join("")
+                })
+                .join("\n")
+        );
+
+        let i = field.values().filter(|x| **x >= 2).count();
+
+        Ok(format!("{}", i))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::run_solution;
+    use crate::util::geom::Vec2;
+    use crate::y2021::day5::{Day5, Line};
+    use crate::Day;
+    use indoc::indoc;
+
+    // PART 1
+
+    #[test]
+    fn test_line() {
+        let x: Vec<(i32, i32)> = Line(Vec2(2, 3), Vec2(2, 7))
+            .iter()
+            .map(|p| (p.0, p.1))
+            .collect();
+        assert_eq!(x, vec![(2, 3), (2, 4), (2, 5), (2, 6), (2, 7)]);
+
+        let y: Vec<(i32, i32)> = Line(Vec2(5, 3), Vec2(1, 3))
+            .iter()
+            .map(|p| (p.0, p.1))
+            .collect();
+        assert_eq!(y, vec![(1, 3), (2, 3), (3, 3), (4, 3), (5, 3)]);
+
+        let z: Vec<(i32, i32)> = Line(Vec2(9, 7), Vec2(7, 9))
+            .walk()
+            .map(|p| (p.0, p.1))
+            .collect();
+        assert_eq!(z, vec![(9, 7), (8, 8), (7, 9)]);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            "5",
+            Day5::solution1(indoc!(
+                "0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2"
+            ))
+            .unwrap()
+        );
+    }
+
+    const INPUT: &str = "2021/day5.txt";
+
+    #[test]
+    fn run_solution1() {
+        run_solution(INPUT, Day5::solution1).unwrap()
+    }
+
+    // PART 2
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            "12",
+            Day5::solution2(indoc!(
+                "0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2"
+            ))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn run_solution2() {
+        run_solution(INPUT, Day5::solution2).unwrap()
+    }
+}
@@ -0,0 +1,167 @@
+use crate::util::{parse_numbers, ParseError};
+use crate::Day;
+use anyhow::Result;
+use itertools::Itertools;
+
+#[derive(Copy, Clone)]
+pub struct Lanternfish(pub u32);
+
+impl Lanternfish {
+    pub fn step(&mut self) -> Option<Lanternfish> {
+        if self.0 == 0 {
+            self.0 = 6;
+            Some(Lanternfish(8))
+        } else {
+            self.0 -= 1;
+            None
+        }
+    }
+}
+
+pub struct LaternfishPopulation {
+    by_age: [u64; 9],
+}
+
+impl LaternfishPopulation {
+    pub fn from_list(xs: Vec<usize>) -> LaternfishPopulation {
+        let mut n: [u64; 9] = [0; 9];
+        for x in xs {
+            n[x] += 1;
+        }
+        LaternfishPopulation { by_age: n }
+    }
+
+    pub fn step(&mut self) {
+        let mut n: [u64; 9] = [0; 9];
+
+        (1..=8).for_each(|age| {
+            n[age - 1] = self.by_age[age];
+        });
+
+        n[6] += self.by_age[0];
+        n[8] += self.by_age[0];
+        self.by_age = n;
+    }
+
+    pub fn len(&self) -> u64 {
+        self.by_age.iter().sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+// steps every single fish, only feasible for a few days
+pub fn simulate_fish(fish: &[usize], days: usize) -> u64 {
+    let mut xs = fish.iter().map(|x| Lanternfish(*x as u32)).collect_vec();
+
+    for _ in 0..days {
+        xs = xs
+            .into_iter()
+            .flat_map(|mut x| x.step().map_or_else(|| vec![x], |n| vec![x, n]))
+            .collect_vec();
+    }
+
+    xs.len() as u64
+}
+
+pub fn simulate_population(fish: &[usize], days: usize) -> u64 {
+    let mut pop = LaternfishPopulation::from_list(fish.to_vec());
+
+    for _ in 0..days {
+        pop.step();
+    }
+
+    pop.len()
+}
+
+pub struct Day6;
+
+impl Day for Day6 {
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Vec<usize>> {
+        let fish = parse_numbers::<usize>(1, input)?;
+
+        // a new fish starts at 8, older ones count down from 6
+        let mut start = 0;
+        for x in input.split(|c: char| c == ',' || c.is_whitespace()) {
+            if x.parse::<usize>().is_ok_and(|age| age > 8) {
+                let line = input.lines().next().unwrap_or("");
+                return Err(
+                    ParseError::new(1, line, start..start + x.len(), "an age from 0 to 8").into(),
+                );
+            }
+            start += x.len() + 1;
+        }
+
+        Ok(fish)
+    }
+
+    fn part1(fish: &Vec<usize>) -> Result<String> {
+        Ok(format!("{}", simulate_fish(fish, 80)))
+    }
+
+    fn part2(fish: &Vec<usize>) -> Result<String> {
+        Ok(format!("{}", simulate_population(fish, 256)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gen::check::Checker;
+    use crate::gen::lanternfish;
+    use crate::run_solution;
+    use crate::util::ParseError;
+    use crate::y2021::day6::{simulate_fish, simulate_population, Day6};
+    use crate::Day;
+    use indoc::indoc;
+
+    // PART 1
+
+    #[test]
+    fn test_part1() {
+        assert_eq!("5934", Day6::solution1(indoc!("3,4,3,1,2")).unwrap());
+    }
+
+    const INPUT: &str = "2021/day6.txt";
+
+    #[test]
+    fn test_parse_error() {
+        let e = Day6::parse("3,4,12,1").err().unwrap();
+        let e = e.downcast::<ParseError>().unwrap();
+        assert_eq!((1, 4..6), (e.line, e.columns));
+        assert_eq!("\"12\"", e.found);
+        assert!(Day6::parse("9").is_err());
+        assert!(Day6::parse("8,0").is_ok());
+    }
+
+    #[test]
+    fn run_solution1() {
+        run_solution(INPUT, Day6::solution1).unwrap()
+    }
+
+    // PART 2
+
+    #[test]
+    fn test_part2() {
+        assert_eq!("26984457539", Day6::solution2(indoc!("3,4,3,1,2")).unwrap());
+    }
+
+    #[test]
+    fn run_solution2() {
+        run_solution(INPUT, Day6::solution2).unwrap()
+    }
+
+    #[test]
+    fn test_differential() {
+        Checker::default().assert_agree::<Day6, _>(
+            lanternfish,
+            8,
+            60,
+            |fish, days| simulate_population(fish, days),
+            |fish, days| simulate_fish(fish, days),
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use crate::util::parse_numbers;
+use crate::Day;
+use anyhow::Result;
+use itertools::Itertools;
+
+pub struct Day7;
+
+impl Day for Day7 {
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Vec<i32>> {
+        Ok(parse_numbers::<i32>(1, input)?
+            .into_iter()
+            .sorted()
+            .collect_vec())
+    }
+
+    fn part1(xs: &Vec<i32>) -> Result<String> {
+        let res: i32 = (*xs.first().unwrap()..=*xs.last().unwrap())
+            .map(|res| xs.iter().map(|x| (res - x).abs()).sum())
+            .min()
+            .unwrap();
+
+        Ok(format!("{}", res))
+    }
+
+    fn part2(xs: &Vec<i32>) -> Result<String> {
+        let res: i32 = (*xs.first().unwrap()..=*xs.last().unwrap())
+            .map(|res| {
+                let ss: i32 = xs
+                    .iter()
+                    .map(|x| {
+                        (0..(res - x).abs())
+                            .enumerate()
+                            .map(|(i, _x)| (i + 1) as i32)
+                            .sum::<i32>()
+                    })
+                    .sum();
+                ss
+            })
+            .sorted()
+            .nth(0)
+            .unwrap();
+
+        Ok(format!("{}", res))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::run_solution;
+    use crate::y2021::day7::Day7;
+    use crate::Day;
+    use indoc::indoc;
+
+    // PART 1
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            "37",
+            Day7::solution1(indoc!("16,1,2,0,4,2,7,1,2,14")).unwrap()
+        );
+    }
+
+    const INPUT: &str = "2021/day7.txt";
+
+    #[test]
+    fn run_solution1() {
+        run_solution(INPUT, Day7::solution1).unwrap()
+    }
+
+    // PART 2
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            "168",
+            Day7::solution2(indoc!("16,1,2,0,4,2,7,1,2,14")).unwrap()
+        );
+    }
+
+    #[test]
+    fn run_solution2() {
+        run_solution(INPUT, Day7::solution2).unwrap()
+    }
+}
@@ -1,34 +1,8 @@
-use crate::util::{parse_lines, parse_lines_regex};
+use crate::util::parse_regex;
+use crate::Day;
 use anyhow::Result;
-use itertools::{izip, Itertools};
-use std::collections::{HashMap, HashSet};
-
-fn solution1(input: &str) -> Result<String> {
-    let xs = parse_lines_regex(input, "^(.+?)\\|(.+?)$")?;
-
-    let ys = xs
-        .iter()
-        .map(|l| {
-            let (v1, v2) = (
-                l[1].as_str().trim().split(" ").collect_vec(),
-                l[2].as_str().trim().split(" ").collect_vec(),
-            );
-
-            (v1, v2)
-        })
-        .collect_vec();
-
-    let sum: usize = ys
-        .iter()
-        .map(|x| {
-            x.1.iter()
-                .filter(|s| s.len() == 2 || s.len() == 3 || s.len() == 4 || s.len() == 7)
-                .count()
-        })
-        .sum();
-
-    Ok(format!("{}", sum))
-}
+use itertools::Itertools;
+use std::collections::HashSet;
 
 struct SegmentHypothesis {
     // index is from top to bottom, left to right
@@ -116,7 +90,7 @@ impl SegmentHypothesis {
             .iter()
             .map(|h| {
                 assert_eq!(h.len(), 1);
-                h.iter().nth(0).unwrap().clone()
+                *h.iter().nth(0).unwrap()
             })
             .join("");
 
@@ -126,61 +100,78 @@ impl SegmentHypothesis {
             .collect_vec();
         idxs.sort();
         (0..=9)
-            .filter(|n| idxs == Self::idxs_by_num(*n as usize))
+            .filter(|n| idxs == Self::idxs_by_num(*n))
             .nth(0)
             .unwrap()
     }
 }
 
-fn solution2(input: &str) -> Result<String> {
-    let xs = parse_lines_regex(input, "^(.+?)\\|(.+?)$")?;
+pub struct Day8;
 
-    let ys = xs
-        .iter()
-        .map(|l| {
-            let (v1, v2) = (
-                l[1].as_str().trim().split(" ").collect_vec(),
-                l[2].as_str().trim().split(" ").collect_vec(),
-            );
-
-            (v1, v2)
-        })
-        .collect_vec();
+impl Day for Day8 {
+    type Input = Vec<(Vec<String>, Vec<String>)>;
 
-    let sum: usize = ys
-        .iter()
-        .map(|x| {
-            let mut hypothesis = SegmentHypothesis::new();
-            x.0.iter().for_each(|x| hypothesis.add(x));
-            x.1.iter().for_each(|x| hypothesis.add(x));
+    fn parse(input: &str) -> Result<Vec<(Vec<String>, Vec<String>)>> {
+        let xs = parse_regex::<(String, String)>(input, "^([a-g ]+)\\|([a-g ]+)$")?;
+        let words = |s: &str| s.split_whitespace().map(|x| x.to_string()).collect_vec();
 
-            hypothesis.infer_trivial();
-            hypothesis.infer_trivial(); // two runs are needed
+        Ok(xs
+            .iter()
+            .map(|(v1, v2)| (words(v1), words(v2)))
+            .collect_vec())
+    }
 
-            let num_str =
+    fn part1(ys: &Vec<(Vec<String>, Vec<String>)>) -> Result<String> {
+        let sum: usize = ys
+            .iter()
+            .map(|x| {
                 x.1.iter()
-                    .map(|x| hypothesis.decode(x).to_string())
-                    .join("");
-            num_str.parse::<usize>().unwrap()
-        })
-        .sum();
+                    .filter(|s| s.len() == 2 || s.len() == 3 || s.len() == 4 || s.len() == 7)
+                    .count()
+            })
+            .sum();
+
+        Ok(format!("{}", sum))
+    }
 
-    Ok(format!("{}", sum))
+    fn part2(ys: &Vec<(Vec<String>, Vec<String>)>) -> Result<String> {
+        let sum: usize = ys
+            .iter()
+            .map(|x| {
+                let mut hypothesis = SegmentHypothesis::new();
+                x.0.iter().for_each(|x| hypothesis.add(x));
+                x.1.iter().for_each(|x| hypothesis.add(x));
+
+                hypothesis.infer_trivial();
+                hypothesis.infer_trivial(); // two runs are needed
+
+                let num_str =
+                    x.1.iter()
+                        .map(|x| hypothesis.decode(x).to_string())
+                        .join("");
+                num_str.parse::<usize>().unwrap()
+            })
+            .sum();
+
+        Ok(format!("{}", sum))
+    }
 }
 
+#[cfg(test)]
 mod tests {
-    use crate::day8::{solution1, solution2};
     use crate::run_solution;
+    use crate::y2021::day8::Day8;
+    use crate::Day;
     use indoc::indoc;
 
-    const INPUT: &'static str = "day8.txt";
+    const INPUT: &str = "2021/day8.txt";
 
     // PART 1
 
     #[test]
     fn test_part1() {
         assert_eq!("26",
-                   solution1(indoc!("be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb |fdgacbe cefdb cefbgd gcbe
+                   Day8::solution1(indoc!("be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb |fdgacbe cefdb cefbgd gcbe
 edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec |fcgedb cgb dgebacf gc
 fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef |cg cg fdcagb cbg
 fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega |efabcd cedba gadfec cb
@@ -194,18 +185,18 @@ gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc |fgae cfgab fg bagce"
 
     #[test]
     fn run_solution1() {
-        run_solution(INPUT, solution1).unwrap()
+        run_solution(INPUT, Day8::solution1).unwrap()
     }
 
     // PART 2
 
     #[test]
     fn test_part2() {
-        assert_eq!("5353", solution2(indoc!("acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab |cdfeb fcadb cdfeb cdbaf")).unwrap());
+        assert_eq!("5353", Day8::solution2(indoc!("acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab |cdfeb fcadb cdfeb cdbaf")).unwrap());
     }
 
     #[test]
     fn run_solution2() {
-        run_solution(INPUT, solution2).unwrap()
+        run_solution(INPUT, Day8::solution2).unwrap()
     }
 }
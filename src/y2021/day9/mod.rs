@@ -0,0 +1,131 @@
+use crate::util::grid::{Grid, Pos};
+use crate::util::parse_digit_grid;
+use crate::viz::{Cell, Frame, Frames};
+use crate::{trace, Day};
+use anyhow::Result;
+use itertools::Itertools;
+use std::collections::HashSet;
+
+fn basin(ys: &Grid<usize>, counted: &mut HashSet<Pos>, pos: Pos) -> usize {
+    if ys[pos] < 9 && counted.insert(pos) {
+        1 + ys
+            .orthogonal(pos)
+            .map(|n| basin(ys, counted, n))
+            .sum::<usize>()
+    } else {
+        0
+    }
+}
+
+// the height map with its low points marked
+pub fn frames(input: &str) -> Result<Frames> {
+    let ys = Day9::parse(input)?;
+    let cells = Grid::from_fn(ys.width(), ys.height(), |p| {
+        match ys.orthogonal(p).all(|n| ys[n] > ys[p]) {
+            true => Cell::heat('v', ys[p] as u8),
+            false => Cell::digit(ys[p] as u8),
+        }
+    });
+    let frame = Frame {
+        caption: "heights, low points are marked with v".to_string(),
+        cells,
+    };
+    Ok(Box::new(std::iter::once(frame)))
+}
+
+pub struct Day9;
+
+impl Day for Day9 {
+    type Input = Grid<usize>;
+
+    fn parse(input: &str) -> Result<Grid<usize>> {
+        Ok(parse_digit_grid(input)?)
+    }
+
+    fn part1(ys: &Grid<usize>) -> Result<String> {
+        let lowest_is = ys
+            .positions()
+            .filter(|&p| ys.orthogonal(p).all(|n| ys[n] > ys[p]))
+            .collect_vec();
+
+        let res: usize = lowest_is
+            .iter()
+            .map(|&p| {
+                let xx = ys[p];
+                trace!("{}", xx);
+                xx + 1
+            })
+            .sum();
+
+        Ok(format!("{}", res))
+    }
+
+    fn part2(ys: &Grid<usize>) -> Result<String> {
+        // every basin flows down to a single low point
+        let mut basins: Vec<usize> = ys
+            .positions()
+            .filter(|&p| ys.orthogonal(p).all(|n| ys[n] > ys[p]))
+            .map(|p| basin(ys, &mut HashSet::new(), p))
+            .collect();
+
+        basins.sort();
+
+        let res_maybe: usize = basins.iter().rev().take(3).product();
+
+        Ok(format!("{}", res_maybe))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::run_solution;
+    use crate::y2021::day9::Day9;
+    use crate::Day;
+    use indoc::indoc;
+
+    const INPUT: &str = "2021/day9.txt";
+
+    // PART 1
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            "15",
+            Day9::solution1(indoc!(
+                "2199943210
+3987894921
+9856789892
+8767896789
+9899965678"
+            ))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn run_solution1() {
+        run_solution(INPUT, Day9::solution1).unwrap()
+    }
+
+    // PART 2
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            "1134",
+            Day9::solution2(indoc!(
+                "2199943210
+3987894921
+9856789892
+8767896789
+9899965678"
+            ))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn run_solution2() {
+        run_solution(INPUT, Day9::solution2).unwrap()
+    }
+}